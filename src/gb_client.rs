@@ -1,44 +1,133 @@
+mod body;
+mod cache;
+mod catalog;
+mod characters;
+mod companies;
+mod dates;
+mod de;
+mod dlcs;
+mod expand;
+mod franchises;
+mod images;
+mod middleware;
+mod paging;
+mod platforms;
+mod ratings;
+mod releases;
+mod resources;
+mod reviews;
+mod search;
+mod taxonomy;
+mod types;
+mod videos;
+
+use futures::future::{BoxFuture, FutureExt, Shared};
+use futures::TryStreamExt;
 use rand::Rng;
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use reqwest_tracing::TracingMiddleware;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub use characters::{Character, Person};
+pub use companies::{Company, CompanyGame};
+pub use dates::ReleaseDate;
+pub use dlcs::Dlc;
+pub use expand::{ExpandField, DEFAULT_EXPAND_CONCURRENCY};
+pub use franchises::{Franchise, FranchiseTimeline};
+pub use images::Image;
+pub use platforms::Platform;
+pub use ratings::{ContentFilter, GameRating, RatingBoard};
+pub use releases::{group_by_region, RegionReleases};
+pub use resources::is_guid;
+pub use reviews::ReviewSummary;
+pub use search::{ResourceType, SearchHit};
+pub use taxonomy::{Taxon, TaxonomyKind};
+pub use videos::{Video, VideoCategory, VideoFilter, VideoQuality, VideoShow};
 
-type Error = Box<dyn std::error::Error + Send + Sync>;
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
 
 // required by GiantBomb otherwise the api fails with: Bad Content type
 const USER_AGENT: &str = "alorg-game-of-the-day-giantbomb";
 
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
-struct GameImage {
+const GIANTBOMB_URL: &str = "https://www.giantbomb.com";
+
+// how many games a name search returns at most
+pub const SEARCH_LIMIT: usize = 5;
+
+// set on every request by `get_json`, never by callers
+const RESERVED_PARAMS: &[&str] = &["api_key", "format"];
+
+// a body past this is refused before it's fully read
+pub const DEFAULT_MAX_BODY_BYTES: usize = 4 * 1024 * 1024;
+
+// how long `raw_get` answers an identical call from memory
+pub const DEFAULT_RAW_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+const RAW_CACHE_CAPACITY: usize = 1000;
+
+// GiantBomb caps list pages at 100 results
+const MAX_PAGE_SIZE: usize = 100;
+
+// fields we ask for on every game, both for details and search results; `description`
+// is only asked for by `get_game`, see `body::DescriptionFilter`
+const GAME_FIELDS: &[&str] = &[
+    "name",
+    "aliases",
+    "site_detail_url",
+    "themes",
+    "platforms",
+    "original_release_date",
+    "image",
+    "id",
+    "guid",
+    "expected_release_year",
+    "expected_release_quarter",
+    "expected_release_month",
+    "expected_release_day",
+    "developers",
+    "deck",
+    "concepts",
+    "characters",
+    "original_game_rating",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct GameImage {
     original_url: Option<String>,
     super_url: Option<String>,
     screen_url: Option<String>,
     screen_large_url: Option<String>,
     medium_url: Option<String>,
     small_url: Option<String>,
-    thumb_url: Option<String>,
+    pub(crate) thumb_url: Option<String>,
     icon_url: Option<String>,
     tiny_url: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug, PartialEq)]
-struct Characteristic {
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Characteristic {
     api_detail_url: String,
     id: i32,
     name: String,
-    site_detail_url: String,
+    site_detail_url: Option<String>,
     abbreviation: Option<String>,
 }
 
-#[derive(Deserialize, Serialize, Debug, Default, PartialEq)]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
 pub struct Game {
-    id: i32,
-    guid: String,
-    image: Option<GameImage>,
-    name: String,
+    pub(crate) id: i32,
+    pub(crate) guid: String,
+    pub(crate) image: Option<GameImage>,
+    pub(crate) name: String,
+    // newline separated alternative names, e.g. "Zelda 3" for "A Link to the Past"
+    pub(crate) aliases: Option<String>,
     deck: Option<String>,
+    // html, only filled in on game details
     description: Option<String>,
-    original_release_date: Option<String>,
+    pub(crate) original_release_date: Option<String>,
     site_detail_url: Option<String>,
     expected_release_day: Option<i32>,
     expected_release_month: Option<i32>,
@@ -49,6 +138,29 @@ pub struct Game {
     developers: Option<Vec<Characteristic>>,
     characters: Option<Vec<Characteristic>>,
     themes: Option<Vec<Characteristic>>,
+    // the ratings of the first release, e.g. `ESRB: M`
+    original_game_rating: Option<Vec<Characteristic>>,
+}
+
+impl Game {
+    // names of the ratings of the first release, e.g. `ESRB: M`
+    pub fn ratings(&self) -> impl Iterator<Item = &str> {
+        self.original_game_rating
+            .iter()
+            .flatten()
+            .map(|rating| rating.name.as_str())
+    }
+
+    // when the game came out, or failing that when it's expected to
+    pub fn release_date(&self) -> Option<ReleaseDate> {
+        ReleaseDate::exact_or_expected(
+            self.original_release_date.as_deref(),
+            self.expected_release_year,
+            self.expected_release_quarter,
+            self.expected_release_month,
+            self.expected_release_day,
+        )
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -56,8 +168,9 @@ struct DetailUrl {
     api_detail_url: String,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-struct GiantBombResponse {
+// every GiantBomb response is wrapped in the same envelope, only `results` changes
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct Envelope<T> {
     // I can't remember what exactly these values can be, but just someting that isn't OK will be enough
     // will be either "OK" | "ERROR"
     error: String,
@@ -67,225 +180,600 @@ struct GiantBombResponse {
     number_of_page_results: i64,
     number_of_total_results: i64,
     status_code: i32,
-    results: Vec<DetailUrl>,
+    results: T,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-struct GiantBombGameResponse {
-    // I can't remember what exactly these values can be, but just someting that isn't OK will be enough
-    // will be either "OK" | "ERROR"
-    error: String,
-    version: String,
-    limit: i32,
+// one page of a list endpoint, without the rest of the envelope noise
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Page<T> {
     offset: i32,
-    number_of_page_results: i64,
-    number_of_total_results: i64,
-    status_code: i32,
-    results: Game,
+    limit: i32,
+    total: i64,
+    results: Vec<T>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-struct GiantBombSearchResponse {
-    // I can't remember what exactly these values can be, but just someting that isn't OK will be enough
-    // will be either "OK" | "ERROR"
-    error: String,
-    version: String,
-    limit: i32,
-    offset: i32,
-    number_of_page_results: i64,
-    number_of_total_results: i64,
-    status_code: i32,
-    results: Vec<Game>,
+impl<T> Page<T> {
+    // pages through a list we already hold in memory
+    pub fn from_vec(items: Vec<T>, offset: i32, limit: i32) -> Self {
+        let total = items.len() as i64;
+        let results = items
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect();
+
+        Page {
+            offset,
+            limit,
+            total,
+            results,
+        }
+    }
+}
+
+impl<T> From<Envelope<Vec<T>>> for Page<T> {
+    fn from(envelope: Envelope<Vec<T>>) -> Self {
+        Page {
+            offset: envelope.offset,
+            limit: envelope.limit,
+            total: envelope.number_of_total_results,
+            results: envelope.results,
+        }
+    }
+}
+
+// the result of a batch lookup, ids GiantBomb doesn't know end up in `missing`
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct GameBatch {
+    games: Vec<Game>,
+    missing: Vec<i32>,
+}
+
+impl GameBatch {
+    pub fn into_games(self) -> Vec<Game> {
+        self.games
+    }
 }
 
+// narrows down which games the games list (and so the random game) picks from
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct GameFilter {
+    pub platform: Option<i32>,
+}
+
+impl GameFilter {
+    // GiantBomb's `filter` param, e.g. `platforms:94`
+    fn to_param(&self) -> Option<String> {
+        let filters = self
+            .platform
+            .iter()
+            .map(|id| format!("platforms:{}", id))
+            .collect::<Vec<_>>();
+
+        if filters.is_empty() {
+            None
+        } else {
+            Some(filters.join(","))
+        }
+    }
+
+    fn apply(&self, mut params: Vec<(&'static str, String)>) -> Vec<(&'static str, String)> {
+        if let Some(filter) = self.to_param() {
+            params.push(("filter", filter));
+        }
+        params
+    }
+}
+
+type GiantBombResponse = Envelope<Vec<DetailUrl>>;
+type GiantBombGameResponse = Envelope<Game>;
+type GiantBombSearchResponse = Envelope<Vec<Game>>;
+
 fn random(max: i64) -> i64 {
     // get random int between 0 and (max - 1)
     rand::thread_rng().gen_range(0..max)
 }
 
-#[tracing::instrument(name = "Max games query", skip(client, token))]
-async fn get_max_games(
-    client: &ClientWithMiddleware,
-    token: &str,
-    api: &str,
-) -> Result<i64, Error> {
-    let url = format!(
-        "{}/api/games/?api_key={}&limit=1&format=json&field_list=api_detail_url",
-        api, token
-    );
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .and_then(|r| match r.error_for_status() {
-            Ok(res) => Ok(res),
-            Err(err) => Err(reqwest_middleware::Error::Reqwest(err)),
-        })?
-        .json::<GiantBombResponse>()
-        .await?;
-
-    Ok(response.number_of_total_results)
-}
+// errors are flattened to strings so the shared future's output can be cloned
+type PendingBody = Shared<BoxFuture<'static, Result<Arc<[u8]>, String>>>;
 
-#[tracing::instrument(name = "Game uri query", skip(client, token, idx), fields(game_idx = %idx))]
-async fn get_game_uri(
-    client: &ClientWithMiddleware,
+// tracing outermost so one span covers every retry, the rate limit innermost so
+// every attempt waits for its own turn
+fn middleware_client(
+    http: &reqwest::Client,
     token: &str,
-    idx: i64,
-    api: &str,
-) -> Result<String, Error> {
-    let url = format!(
-        "{}/api/games/?api_key={}&limit=1&format=json&offset={}&field_list=api_detail_url",
-        api, token, idx
-    );
-
-    let response = client
-        .get(url)
-        .send()
-        .await
-        .and_then(|r| match r.error_for_status() {
-            Ok(res) => Ok(res),
-            Err(err) => Err(reqwest_middleware::Error::Reqwest(err)),
-        })?
-        .json::<GiantBombResponse>()
-        .await?;
-
-    let url = response.results.get(0).map(|detail| &detail.api_detail_url);
-    match url {
-        None => Ok("".to_string()),
-        Some(url) => Ok(url.to_string()),
+    max_retries: u32,
+    rate_limit: Option<&Arc<middleware::RateLimit>>,
+) -> ClientWithMiddleware {
+    let mut builder = ClientBuilder::new(http.clone())
+        .with(TracingMiddleware)
+        .with(middleware::Redact {
+            token: token.to_string(),
+        })
+        .with(middleware::Retry { max_retries });
+    if let Some(rate_limit) = rate_limit {
+        builder = builder.with_arc(rate_limit.clone());
     }
+    builder.build()
 }
 
-#[tracing::instrument(name = "Game details query", skip(client, token, uri), fields(giantbomb_uri = %uri))]
-async fn get_game_details(
-    client: &ClientWithMiddleware,
-    token: &str,
-    uri: &str,
-) -> Result<Game, Error> {
-    let url = format!(
-        "{}?api_key={}&format=json&field_list={}",
-        uri,
-        token,
-        [
-            "name",
-            "site_detail_url",
-            "themes",
-            "platforms",
-            "original_release_date",
-            "image",
-            "id",
-            "guid",
-            "expected_release_year",
-            "expected_release_quarter",
-            "expected_release_month",
-            "expected_release_day",
-            "developers",
-            "deck",
-            // "description", // mostly html formatted nonsense that sometimes is huge in bytes
-            "concepts",
-            "characters",
-        ]
-        .join(",")
-    );
-
-    let response = client
-        .get(url)
-        .send()
+#[derive(Clone)]
+pub struct GiantBombClient {
+    client: ClientWithMiddleware,
+    // the plain client under `client`, kept so the builders can restack the middleware
+    http: reqwest::Client,
+    max_retries: u32,
+    // shared by every clone, GiantBomb counts calls per api key
+    rate_limit: Option<Arc<middleware::RateLimit>>,
+    token: String,
+    api_url: String,
+    // GiantBomb's `/types`, fetched once and shared by every clone
+    types: Arc<Mutex<Option<types::PendingTypes>>>,
+    // detail payloads behind `api_detail_url` references, see `expand_game`
+    expanded: Arc<expand::ExpandCache>,
+    // shared by every clone, so parallel requests don't multiply the expand fan-out
+    expand_slots: Arc<tokio::sync::Semaphore>,
+    // upstream calls currently running, keyed by their redacted url
+    in_flight: Arc<Mutex<HashMap<String, PendingBody>>>,
+    // `raw_get` responses, keyed by their redacted url
+    raw_cache: Arc<cache::TtlCache<Envelope<serde_json::Value>>>,
+    // game counts per filter, so the random game skips `get_max_games` most of the time
+    catalog_sizes: Arc<catalog::CatalogSizes>,
+    max_body_bytes: usize,
+}
+
+impl GiantBombClient {
+    pub fn new(token: &str) -> Self {
+        Self::with_api_url(token, GIANTBOMB_URL)
+    }
+
+    // so that I can test the client with a mock uri
+    pub fn with_api_url(token: &str, api_url: &str) -> Self {
+        let http = reqwest::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()
+            .expect("failed to build reqwest client");
+
+        GiantBombClient {
+            client: middleware_client(&http, token, 0, None),
+            http,
+            max_retries: 0,
+            rate_limit: None,
+            token: token.to_string(),
+            api_url: api_url.to_string(),
+            types: Arc::default(),
+            expanded: Arc::default(),
+            expand_slots: Arc::new(tokio::sync::Semaphore::new(
+                expand::DEFAULT_EXPAND_CONCURRENCY,
+            )),
+            in_flight: Arc::default(),
+            raw_cache: Arc::new(cache::TtlCache::new(
+                DEFAULT_RAW_CACHE_TTL,
+                RAW_CACHE_CAPACITY,
+            )),
+            catalog_sizes: Arc::default(),
+            max_body_bytes: DEFAULT_MAX_BODY_BYTES,
+        }
+    }
+
+    // GiantBomb responses bigger than this fail instead of being read into memory
+    pub fn with_max_body_bytes(mut self, max_body_bytes: usize) -> Self {
+        self.max_body_bytes = max_body_bytes;
+        self
+    }
+
+    // how long `raw_get` keeps a response; zero turns its cache off
+    pub fn with_raw_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.raw_cache = Arc::new(cache::TtlCache::new(ttl, RAW_CACHE_CAPACITY));
+        self
+    }
+
+    // failed calls (connection errors, timeouts, 429s and 5xx) are tried this many more times
+    pub fn with_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self.restack();
+        self
+    }
+
+    // at most `burst` calls at once, then one per `interval`; a zero interval turns it off
+    pub fn with_rate_limit(mut self, burst: u32, interval: Duration) -> Self {
+        self.rate_limit = if interval.is_zero() {
+            None
+        } else {
+            Some(Arc::new(middleware::RateLimit::new(burst, interval)))
+        };
+        self.restack();
+        self
+    }
+
+    fn restack(&mut self) {
+        self.client = middleware_client(
+            &self.http,
+            &self.token,
+            self.max_retries,
+            self.rate_limit.as_ref(),
+        );
+    }
+
+    // builds the url for a resource relative to the api root, e.g. `games/`
+    fn resource_url(&self, resource: &str) -> String {
+        format!("{}/api/{}", self.api_url, resource)
+    }
+
+    // e.g. `/api/platform/3045-94/`
+    fn detail_url(&self, resource: resources::Resource, id: i32) -> String {
+        self.resource_url(&format!("{}/{}/", resource.detail, resource.guid(id)))
+    }
+
+    fn list_url(&self, resource: resources::Resource) -> String {
+        self.resource_url(&format!("{}/", resource.list))
+    }
+
+    // reqwest errors carry the full url, token included, and those end up in our logs
+    fn redact(&self, message: &str) -> String {
+        if self.token.is_empty() {
+            return message.to_string();
+        }
+        message.replace(&self.token, "REDACTED")
+    }
+
+    // `url` with the token and json format attached, then `params`
+    fn request_url(&self, url: &str, params: &[(&str, String)]) -> Result<reqwest::Url, Error> {
+        Ok(reqwest::Url::parse_with_params(
+            url,
+            [("api_key", self.token.as_str()), ("format", "json")]
+                .iter()
+                .copied()
+                .chain(params.iter().map(|(name, value)| (*name, value.as_str()))),
+        )?)
+    }
+
+    // sends a GET to `url` with the token and json format attached and decodes the envelope.
+    // identical requests running at the same time share a single upstream call
+    async fn get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        params: &[(&str, String)],
+    ) -> Result<Envelope<T>, Error> {
+        let url = self.request_url(url, params)?;
+        let key = self.redact(url.as_str());
+
+        let pending = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            in_flight
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    let client = self.clone();
+                    async move {
+                        let body = client
+                            .fetch_body(url)
+                            .await
+                            .map_err(|err| client.redact(&err.to_string()));
+                        // cleared by the call itself, even when nobody waits for it anymore;
+                        // while it runs no other call can be filed under the same key
+                        client.in_flight.lock().unwrap().remove(&key);
+                        body
+                    }
+                    .boxed()
+                    .shared()
+                })
+                .clone()
+        };
+
+        let body = pending.await;
+
+        let response: Envelope<T> = serde_json::from_slice(&body?)?;
+        if response.error != "OK" {
+            return Err(format!(
+                "GiantBomb returned error ({}): {}",
+                response.status_code, response.error
+            )
+            .into());
+        }
+
+        Ok(response)
+    }
+
+    // reads the body chunk by chunk, dropping oversize descriptions on the way (see
+    // `body::DescriptionFilter`) and cutting off early once what's kept is over the cap
+    async fn fetch_body(&self, url: reqwest::Url) -> Result<Arc<[u8]>, Error> {
+        let mut response =
+            self.client
+                .get(url)
+                .send()
+                .await
+                .and_then(|r| match r.error_for_status() {
+                    Ok(res) => Ok(res),
+                    Err(err) => Err(reqwest_middleware::Error::Reqwest(err)),
+                })?;
+
+        let mut filter = body::DescriptionFilter::default();
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            filter.feed(&chunk, &mut body);
+            if body.len() > self.max_body_bytes {
+                return Err(
+                    format!("GiantBomb response over {} bytes", self.max_body_bytes).into(),
+                );
+            }
+        }
+
+        Ok(body.into())
+    }
+
+    // anything under `/api/` we don't model yet, e.g. `game/3030-1/` with a `field_list`.
+    // goes through the same request path as the typed calls (rate limit, retries, sharing of
+    // identical calls, redaction) and answers a repeated call from memory for a while, see
+    // `with_raw_cache_ttl`; the token and format are always ours, whatever `params` says
+    pub async fn raw_get(
+        &self,
+        resource: &str,
+        params: &[(&str, String)],
+    ) -> Result<Envelope<serde_json::Value>, Error> {
+        let url = self.resource_url(resource);
+        let params = params
+            .iter()
+            .filter(|(name, _)| !RESERVED_PARAMS.contains(name))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let key = self.redact(self.request_url(&url, &params)?.as_str());
+        if let Some(response) = self.raw_cache.get(&key) {
+            return Ok(response);
+        }
+
+        let response: Envelope<serde_json::Value> = self.get_json(&url, &params).await?;
+        self.raw_cache.insert(&key, response.clone());
+        Ok(response)
+    }
+
+    // walks every page of a list endpoint until GiantBomb runs out of results
+    async fn get_all<T: DeserializeOwned + 'static>(
+        &self,
+        url: &str,
+        params: &[(&'static str, String)],
+    ) -> Result<Vec<T>, Error> {
+        self.paginate(
+            url.to_string(),
+            params.to_vec(),
+            paging::Paging::Offset,
+            MAX_PAGE_SIZE,
+        )
+        .try_collect()
         .await
-        .and_then(|r| match r.error_for_status() {
-            Ok(res) => Ok(res),
-            Err(err) => Err(reqwest_middleware::Error::Reqwest(err)),
-        })?
-        .json::<GiantBombGameResponse>()
-        .await?;
-
-    Ok(response.results)
+    }
+
+    #[tracing::instrument(name = "Max games query", skip(self))]
+    async fn get_max_games(&self, filter: &GameFilter) -> Result<i64, Error> {
+        let params = filter.apply(vec![
+            ("limit", "1".to_string()),
+            ("field_list", "api_detail_url".to_string()),
+        ]);
+        let response: GiantBombResponse = self
+            .get_json(&self.list_url(resources::GAME), &params)
+            .await?;
+
+        Ok(response.number_of_total_results)
+    }
+
+    #[tracing::instrument(name = "Game uri query", skip(self, idx), fields(game_idx = %idx))]
+    async fn get_game_uri(&self, idx: i64, filter: &GameFilter) -> Result<String, Error> {
+        let params = filter.apply(vec![
+            ("limit", "1".to_string()),
+            ("offset", idx.to_string()),
+            ("field_list", "api_detail_url".to_string()),
+        ]);
+        let response: GiantBombResponse = self
+            .get_json(&self.list_url(resources::GAME), &params)
+            .await?;
+
+        let url = response
+            .results
+            .first()
+            .map(|detail| &detail.api_detail_url);
+        match url {
+            None => Ok("".to_string()),
+            Some(url) => Ok(url.to_string()),
+        }
+    }
+
+    #[tracing::instrument(name = "Game details query", skip(self, uri), fields(giantbomb_uri = %uri))]
+    async fn get_game_details(&self, uri: &str) -> Result<Game, Error> {
+        self.get_game_fields(uri, GAME_FIELDS.join(",")).await
+    }
+
+    async fn get_game_fields(&self, uri: &str, fields: String) -> Result<Game, Error> {
+        let response: GiantBombGameResponse = self.get_json(uri, &[("field_list", fields)]).await?;
+
+        Ok(response.results)
+    }
+
+    // the game with its html description, unless that's one of the huge ones
+    #[tracing::instrument(name = "Get game", skip(self))]
+    pub async fn get_game(&self, id: i32) -> Result<Game, Error> {
+        let fields = format!("{},description", GAME_FIELDS.join(","));
+        self.get_game_fields(&self.detail_url(resources::GAME, id), fields)
+            .await
+    }
+
+    // a random game from the whole catalog
+    pub async fn get_random_game(&self) -> Result<Game, Error> {
+        self.get_random_game_matching(&GameFilter::default()).await
+    }
+
+    #[tracing::instrument(name = "Get random game", skip(self))]
+    pub async fn get_random_game_matching(&self, filter: &GameFilter) -> Result<Game, Error> {
+        let max_games = self.catalog_size(filter).await?;
+        if max_games == 0 {
+            return Err("no games match the filter".into());
+        }
+
+        let idx = random(max_games);
+
+        // this game uri has a HUGE detail payload
+        // let game_uri = "https://www.giantbomb.com/api/game/3030-1156/";
+        let game_uri = self.get_game_uri(idx, filter).await?;
+
+        let game = self.get_game_details(&game_uri).await?;
+        Ok(game)
+    }
+
+    #[tracing::instrument(name = "List games", skip(self))]
+    pub async fn list_games(
+        &self,
+        filter: &GameFilter,
+        offset: i32,
+        limit: i32,
+    ) -> Result<Page<Game>, Error> {
+        let params = filter.apply(vec![
+            ("offset", offset.to_string()),
+            ("limit", limit.to_string()),
+            ("field_list", GAME_FIELDS.join(",")),
+        ]);
+        let response: Envelope<Vec<Game>> = self
+            .get_json(&self.list_url(resources::GAME), &params)
+            .await?;
+
+        Ok(response.into())
+    }
+
+    // uses the list endpoint's `id:1|2|3` filter to fetch many resources per request
+    async fn fetch_by_ids<T: DeserializeOwned>(
+        &self,
+        resource: resources::Resource,
+        ids: &[i32],
+        fields: &[&str],
+    ) -> Result<Vec<T>, Error> {
+        let mut results = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(MAX_PAGE_SIZE) {
+            let ids = chunk
+                .iter()
+                .map(i32::to_string)
+                .collect::<Vec<_>>()
+                .join("|");
+            let response: Envelope<Vec<T>> = self
+                .get_json(
+                    &self.list_url(resource),
+                    &[
+                        ("filter", format!("id:{}", ids)),
+                        ("limit", chunk.len().to_string()),
+                        ("field_list", fields.join(",")),
+                    ],
+                )
+                .await?;
+            results.extend(response.results);
+        }
+
+        Ok(results)
+    }
+
+    // many games in as few requests as possible, in the order of `ids`
+    #[tracing::instrument(name = "Batch games query", skip(self))]
+    pub async fn get_games_by_ids(&self, ids: &[i32]) -> Result<GameBatch, Error> {
+        // a repeated id only shows up once, at its first position
+        let mut seen = HashSet::new();
+        let unique = ids
+            .iter()
+            .copied()
+            .filter(|id| seen.insert(*id))
+            .collect::<Vec<_>>();
+
+        let mut found = self
+            .fetch_by_ids::<Game>(resources::GAME, &unique, GAME_FIELDS)
+            .await?
+            .into_iter()
+            .map(|game| (game.id, game))
+            .collect::<HashMap<_, _>>();
+
+        let mut batch = GameBatch::default();
+        for id in unique {
+            match found.remove(&id) {
+                Some(game) => batch.games.push(game),
+                None => batch.missing.push(id),
+            }
+        }
+        Ok(batch)
+    }
+
+    pub async fn search_by_game_name(&self, search_term: &str) -> Result<Vec<Game>, Error> {
+        let response: GiantBombSearchResponse = self
+            .get_json(
+                &self.resource_url("search"),
+                &[
+                    ("limit", SEARCH_LIMIT.to_string()),
+                    ("resources", "game".to_string()),
+                    ("field_list", GAME_FIELDS.join(",")),
+                    ("query", search_term.to_string()),
+                ],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
 }
 
-#[tracing::instrument(name = "Get random game", skip(token))]
+// the original token-only entry points, each one a client pointed at GiantBomb
+#[allow(dead_code)]
 pub async fn get_random_game(token: &str) -> Result<Game, Error> {
-    fetch_game(token, "https://www.giantbomb.com").await
+    fetch_game(token, GIANTBOMB_URL).await
 }
 
+#[allow(dead_code)]
 pub async fn search_by_game_name(token: &str, search_term: &str) -> Result<Vec<Game>, Error> {
-    let client = ClientBuilder::new(
-        reqwest::Client::builder()
-            .user_agent(USER_AGENT)
-            .build()
-            .expect("failed to build reqwest client"),
-    )
-    .with(TracingMiddleware)
-    .build();
-
-    let url  =  format!("https://www.giantbomb.com/api/search?api_key={}&limit=5&format=json&resources=game&field_list={}&query={}", token, [
-            "name",
-            "site_detail_url",
-            "themes",
-            "platforms",
-            "original_release_date",
-            "image",
-            "id",
-            "guid",
-            "expected_release_year",
-            "expected_release_quarter",
-            "expected_release_month",
-            "expected_release_day",
-            "developers",
-            "deck",
-            // "description", // mostly html formatted nonsense that sometimes is huge in bytes
-            "concepts",
-            "characters",
-        ].join(","), search_term);
-
-    let response = client
-        .get(url)
-        .send()
+    GiantBombClient::new(token)
+        .search_by_game_name(search_term)
         .await
-        .and_then(|r| match r.error_for_status() {
-            Ok(res) => Ok(res),
-            Err(err) => Err(reqwest_middleware::Error::Reqwest(err)),
-        })?
-        .json::<GiantBombSearchResponse>()
-        .await?;
-
-    Ok(response.results)
 }
 
 // so that I can test the client with a mock uri
+#[allow(dead_code)]
 async fn fetch_game(token: &str, gb_api_url: &str) -> Result<Game, Error> {
-    let client = ClientBuilder::new(
-        reqwest::Client::builder()
-            .user_agent(USER_AGENT)
-            .build()
-            .expect("failed to build reqwest client"),
-    )
-    .with(TracingMiddleware)
-    .build();
-
-    let max_games = get_max_games(&client, token, gb_api_url).await?;
-
-    let idx = random(max_games);
-
-    // this game uri has a HUGE detail payload
-    // let game_uri = "https://www.giantbomb.com/api/game/3030-1156/";
-    let game_uri = get_game_uri(&client, token, idx, gb_api_url).await?;
-
-    let game = get_game_details(&client, token, &game_uri).await?;
-    Ok(game)
+    GiantBombClient::with_api_url(token, gb_api_url)
+        .get_random_game()
+        .await
 }
 
 #[cfg(test)]
-mod tests {
+// the original tests compare booleans with `assert_eq!`
+#[allow(clippy::bool_assert_comparison)]
+pub(crate) mod tests {
+    use crate::gb_client::body::MAX_DESCRIPTION_BYTES;
     use crate::gb_client::fetch_game;
     use crate::gb_client::DetailUrl;
     use crate::gb_client::Game;
+    use crate::gb_client::GiantBombClient;
     use crate::gb_client::GiantBombGameResponse;
     use crate::gb_client::GiantBombResponse;
+    use crate::gb_client::GAME_FIELDS;
     use wiremock::matchers::{method, path, query_param};
     use wiremock::ResponseTemplate;
     use wiremock::{Mock, MockServer};
 
+    // an OK GiantBomb response around `results`, counted as a single page
+    pub(crate) fn envelope(results: serde_json::Value) -> serde_json::Value {
+        let count = results.as_array().map_or(1, Vec::len);
+        paged_envelope(results, 0, count)
+    }
+
+    // `results` as the page at `offset` of a list `total` long
+    pub(crate) fn paged_envelope(
+        results: serde_json::Value,
+        offset: usize,
+        total: usize,
+    ) -> serde_json::Value {
+        let count = results.as_array().map_or(1, Vec::len);
+        serde_json::json!({
+            "error": "OK",
+            "version": "1.0",
+            "limit": 100,
+            "offset": offset,
+            "number_of_page_results": count,
+            "number_of_total_results": total,
+            "status_code": 1,
+            "results": results
+        })
+    }
+
     #[tokio::test]
     async fn throws_error_when_max_games_returns_non_ok() {
         // Arrange
@@ -305,6 +793,276 @@ mod tests {
         assert_eq!(result.is_err(), true);
     }
 
+    #[tokio::test]
+    async fn raw_get_keeps_our_token_and_redacts_it_from_errors() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _ok_guard = Mock::given(method("GET"))
+            .and(path("/api/game/3030-1/"))
+            .and(query_param("api_key", "secret_token"))
+            .and(query_param("field_list", "name"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(envelope(serde_json::json!({ "name": "Metroid" }))),
+            )
+            .named("GET raw game")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _failing_guard = Mock::given(method("GET"))
+            .and(path("/api/broken/"))
+            .respond_with(ResponseTemplate::new(500))
+            .named("GET broken")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("secret_token", &mock_gb_server.uri());
+
+        // Act
+        let game = client
+            .raw_get(
+                "game/3030-1/",
+                &[
+                    ("field_list", "name".to_string()),
+                    ("api_key", "someone_elses_token".to_string()),
+                ],
+            )
+            .await
+            .unwrap();
+        let err = client.raw_get("broken/", &[]).await.unwrap_err();
+
+        // Assert
+        assert_eq!(game.results["name"], "Metroid");
+        assert!(!err.to_string().contains("secret_token"));
+        assert!(err.to_string().contains("REDACTED"));
+    }
+
+    #[tokio::test]
+    async fn raw_get_retries_server_errors() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _failing_guard = Mock::given(method("GET"))
+            .and(path("/api/game/3030-1/"))
+            .respond_with(ResponseTemplate::new(503))
+            .named("GET raw game failing")
+            .up_to_n_times(1)
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _ok_guard = Mock::given(method("GET"))
+            .and(path("/api/game/3030-1/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(envelope(serde_json::json!({ "name": "Metroid" }))),
+            )
+            .named("GET raw game")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .with_retries(1)
+            .with_rate_limit(1, std::time::Duration::from_millis(10));
+
+        // Act
+        let game = client.raw_get("game/3030-1/", &[]).await.unwrap();
+
+        // Assert
+        assert_eq!(game.results["name"], "Metroid");
+    }
+
+    #[tokio::test]
+    async fn raw_get_answers_repeated_calls_from_its_cache_until_they_expire() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/accessory/3000-5/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(envelope(serde_json::json!({ "name": "Power Glove" }))),
+            )
+            .named("GET raw accessory")
+            .expect(2)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .with_raw_cache_ttl(std::time::Duration::from_millis(200));
+
+        // Act
+        let first = client.raw_get("accessory/3000-5/", &[]).await.unwrap();
+        let cached = client.raw_get("accessory/3000-5/", &[]).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        let expired = client.raw_get("accessory/3000-5/", &[]).await.unwrap();
+
+        // Assert
+        assert_eq!(first.results["name"], "Power Glove");
+        assert_eq!(cached.results, first.results);
+        assert_eq!(expired.results, first.results);
+    }
+
+    #[tokio::test]
+    async fn batch_lookup_keeps_input_order_and_reports_missing_ids() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .and(query_param("filter", "id:3|1|2"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([
+                    { "id": 1, "guid": "3030-1", "name": "One" },
+                    { "id": 3, "guid": "3030-3", "name": "Three" }
+                ]))),
+            )
+            .named("GET games by ids")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let batch = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_games_by_ids(&[3, 1, 2, 3])
+            .await
+            .unwrap();
+
+        // Assert
+        let ids = batch.games.iter().map(|game| game.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![3, 1]);
+        assert_eq!(batch.missing, vec![2]);
+    }
+
+    #[tokio::test]
+    async fn identical_concurrent_calls_share_one_upstream_request() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/game/3030-1/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(envelope(
+                        serde_json::json!({ "id": 1, "guid": "3030-1", "name": "Metroid" }),
+                    ))
+                    .set_delay(std::time::Duration::from_millis(200)),
+            )
+            .named("GET game details")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+
+        // Act
+        let (first, second, third) =
+            futures::join!(client.get_game(1), client.get_game(1), client.get_game(1));
+
+        // Assert
+        assert_eq!(first.unwrap().name, "Metroid");
+        assert_eq!(second.unwrap().name, "Metroid");
+        assert_eq!(third.unwrap().name, "Metroid");
+        assert!(client.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_finished_call_nobody_waited_for_is_not_reused() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _failing_guard = Mock::given(method("GET"))
+            .and(path("/api/game/3030-1/"))
+            .respond_with(
+                ResponseTemplate::new(500).set_delay(std::time::Duration::from_millis(200)),
+            )
+            .named("GET game details failing")
+            .up_to_n_times(1)
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _ok_guard = Mock::given(method("GET"))
+            .and(path("/api/game/3030-1/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(envelope(
+                serde_json::json!({ "id": 1, "guid": "3030-1", "name": "Metroid" }),
+            )))
+            .named("GET game details")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+
+        // Act
+        // the caller gives up before GiantBomb answers, then the call finishes without it
+        let cancelled =
+            tokio::time::timeout(std::time::Duration::from_millis(50), client.get_game(1)).await;
+        let abandoned = client
+            .in_flight
+            .lock()
+            .unwrap()
+            .values()
+            .next()
+            .cloned()
+            .unwrap();
+        let abandoned = abandoned.await;
+        let game = client.get_game(1).await;
+
+        // Assert
+        assert!(cancelled.is_err());
+        assert!(abandoned.is_err());
+        assert!(client.in_flight.lock().unwrap().is_empty());
+        assert_eq!(game.unwrap().name, "Metroid");
+    }
+
+    #[tokio::test]
+    async fn drops_oversize_descriptions_before_they_count_against_the_body_cap() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let game_response = |id: i32, deck: &str, description: String| {
+            envelope(serde_json::json!({
+                "id": id,
+                "guid": format!("3030-{}", id),
+                "name": "Game",
+                "deck": deck,
+                "description": description
+            }))
+        };
+        let huge = "x".repeat(200 * 1024);
+        for (id, deck, description) in &[
+            (1, "", "<p>short</p>".to_string()),
+            (2, "", "x".repeat(MAX_DESCRIPTION_BYTES + 1)),
+            (3, "", huge.clone()),
+            // over the cap with or without its description
+            (4, huge.as_str(), "<p>short</p>".to_string()),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(format!("/api/game/3030-{}/", id)))
+                .and(query_param(
+                    "field_list",
+                    format!("{},description", GAME_FIELDS.join(",")).as_str(),
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(game_response(
+                    *id,
+                    deck,
+                    description.clone(),
+                )))
+                .expect(1)
+                .mount(&mock_gb_server)
+                .await;
+        }
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .with_max_body_bytes(100 * 1024);
+
+        // Act
+        let short = client.get_game(1).await.unwrap();
+        let long = client.get_game(2).await.unwrap();
+        let huge = client.get_game(3).await.unwrap();
+        let too_big = client.get_game(4).await;
+
+        // Assert
+        assert_eq!(short.description.as_deref(), Some("<p>short</p>"));
+        assert_eq!(long.description, None);
+        assert_eq!(long.name, "Game");
+        assert_eq!(huge.description, None);
+        assert_eq!(huge.name, "Game");
+        assert!(too_big
+            .unwrap_err()
+            .to_string()
+            .contains("over 102400 bytes"));
+    }
+
     #[tokio::test]
     async fn throws_error_when_game_uri_returns_non_ok() {
         // Arrange
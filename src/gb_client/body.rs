@@ -0,0 +1,179 @@
+// descriptions are html and a few run into megabytes (3030-1156 for one). cut html is broken
+// markup, so one over the cap is dropped rather than truncated
+pub const MAX_DESCRIPTION_BYTES: usize = 64 * 1024;
+
+enum Scope {
+    Object { expecting_key: bool },
+    Array,
+}
+
+// what the string being read is, and so what happens to its bytes
+enum Text {
+    Key,
+    Value,
+    // held back until it either closes under the cap, or it doesn't and becomes `null`
+    Description(Vec<u8>),
+    Dropped,
+}
+
+// scans a json body as the chunks come in and passes everything through except `description`
+// strings over MAX_DESCRIPTION_BYTES (as sent, escapes included), which become `null` without
+// ever being held in full. it only tracks enough of the json to tell keys from values, the
+// body is still parsed as a whole once it's complete
+#[derive(Default)]
+pub struct DescriptionFilter {
+    scopes: Vec<Scope>,
+    text: Option<Text>,
+    escaped: bool,
+    // the start of the last key, enough to tell whether it's `description`
+    key: Vec<u8>,
+    // the value about to start belongs to a `description` key
+    description_next: bool,
+}
+
+const DESCRIPTION: &[u8] = b"description";
+
+impl DescriptionFilter {
+    pub fn feed(&mut self, chunk: &[u8], out: &mut Vec<u8>) {
+        for &byte in chunk {
+            match self.text.take() {
+                Some(text) => self.text = self.string_byte(text, byte, out),
+                None => self.structure_byte(byte, out),
+            }
+        }
+    }
+
+    fn string_byte(&mut self, mut text: Text, byte: u8, out: &mut Vec<u8>) -> Option<Text> {
+        let closing = byte == b'"' && !self.escaped;
+        self.escaped = !self.escaped && byte == b'\\';
+
+        if closing {
+            match text {
+                Text::Key => {
+                    out.push(byte);
+                    self.description_next = self.key == DESCRIPTION;
+                }
+                Text::Value => out.push(byte),
+                Text::Description(mut held) => {
+                    held.push(byte);
+                    out.extend_from_slice(&held);
+                }
+                Text::Dropped => out.extend_from_slice(b"null"),
+            }
+            return None;
+        }
+
+        match &mut text {
+            Text::Key => {
+                out.push(byte);
+                if self.key.len() <= DESCRIPTION.len() {
+                    self.key.push(byte);
+                }
+            }
+            Text::Value => out.push(byte),
+            Text::Description(held) => {
+                held.push(byte);
+                // the opening quote is held too
+                if held.len() > MAX_DESCRIPTION_BYTES + 1 {
+                    return Some(Text::Dropped);
+                }
+            }
+            Text::Dropped => {}
+        }
+        Some(text)
+    }
+
+    fn structure_byte(&mut self, byte: u8, out: &mut Vec<u8>) {
+        match byte {
+            b'"' => {
+                let text = match self.scopes.last() {
+                    Some(Scope::Object {
+                        expecting_key: true,
+                    }) => {
+                        self.key.clear();
+                        Text::Key
+                    }
+                    _ if self.description_next => Text::Description(vec![byte]),
+                    _ => Text::Value,
+                };
+                if !matches!(text, Text::Description(_)) {
+                    out.push(byte);
+                }
+                self.description_next = false;
+                self.text = Some(text);
+                return;
+            }
+            b'{' => self.scopes.push(Scope::Object {
+                expecting_key: true,
+            }),
+            b'[' => self.scopes.push(Scope::Array),
+            b'}' | b']' => {
+                self.scopes.pop();
+            }
+            b',' | b':' => {
+                if let Some(Scope::Object { expecting_key }) = self.scopes.last_mut() {
+                    *expecting_key = byte == b',';
+                }
+            }
+            _ => {}
+        }
+        if !byte.is_ascii_whitespace() && byte != b':' {
+            self.description_next = false;
+        }
+        out.push(byte);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DescriptionFilter, MAX_DESCRIPTION_BYTES};
+
+    fn filtered(body: &str, chunk_size: usize) -> serde_json::Value {
+        let mut filter = DescriptionFilter::default();
+        let mut out = Vec::new();
+        for chunk in body.as_bytes().chunks(chunk_size) {
+            filter.feed(chunk, &mut out);
+        }
+        serde_json::from_slice(&out).unwrap()
+    }
+
+    #[test]
+    fn passes_everything_but_oversize_descriptions_through() {
+        let body = serde_json::json!({
+            "results": {
+                "name": "a \"description\"",
+                "aliases": "description",
+                "description": "<p>short \\ \"quoted\"</p>",
+                "platforms": [{ "name": "NES", "description": null }]
+            }
+        })
+        .to_string();
+
+        for chunk_size in &[1, 7, body.len()] {
+            assert_eq!(
+                filtered(&body, *chunk_size),
+                serde_json::from_str::<serde_json::Value>(&body).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn drops_oversize_descriptions_wherever_the_chunks_split() {
+        let huge = "<p>\"x\"</p>".repeat(MAX_DESCRIPTION_BYTES / 8);
+        let body = serde_json::json!({
+            "results": [
+                { "id": 1, "description": huge, "name": "One" },
+                { "id": 2, "description": "<p>kept</p>", "name": "Two" }
+            ]
+        })
+        .to_string();
+
+        for chunk_size in &[1, 4096, body.len()] {
+            let results = &filtered(&body, *chunk_size)["results"];
+            assert_eq!(results[0]["description"], serde_json::Value::Null);
+            assert_eq!(results[0]["name"], "One");
+            assert_eq!(results[1]["description"], "<p>kept</p>");
+            assert_eq!(results[1]["name"], "Two");
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use super::{Error, GameFilter, GiantBombClient};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// the total game count moves a few times a day at most
+const DEFAULT_CATALOG_TTL: Duration = Duration::from_secs(6 * 60 * 60);
+// one per platform is a few hundred at most, this is just a ceiling
+const CATALOG_CAPACITY: usize = 1000;
+// upstream calls one background refresh may spend, the most recently asked for filters first
+const MAX_REFRESH_PER_PASS: usize = 20;
+
+struct CatalogEntry {
+    size: i64,
+    fetched: Instant,
+    // last time a random game asked for this filter, idle ones aren't refreshed
+    requested: Instant,
+}
+
+// how many games match each filter the random game has been asked for
+pub struct CatalogSizes {
+    entries: Mutex<HashMap<GameFilter, CatalogEntry>>,
+    ttl: Duration,
+}
+
+impl Default for CatalogSizes {
+    fn default() -> Self {
+        CatalogSizes {
+            entries: Mutex::default(),
+            ttl: DEFAULT_CATALOG_TTL,
+        }
+    }
+}
+
+impl CatalogSizes {
+    // also marks the filter as still in use, fresh or not
+    fn fresh(&self, filter: &GameFilter) -> Option<i64> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.get_mut(filter)?;
+        entry.requested = Instant::now();
+        Some(entry.size).filter(|_| entry.fetched.elapsed() < self.ttl)
+    }
+
+    // a filter nothing matches (e.g. a made up platform id) is never kept
+    fn insert(&self, filter: &GameFilter, size: i64) {
+        let mut entries = self.entries.lock().unwrap();
+        if size <= 0 {
+            entries.remove(filter);
+            return;
+        }
+
+        if entries.len() >= CATALOG_CAPACITY && !entries.contains_key(filter) {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.requested)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        let now = Instant::now();
+        entries
+            .entry(filter.clone())
+            .and_modify(|entry| {
+                entry.size = size;
+                entry.fetched = now;
+            })
+            .or_insert(CatalogEntry {
+                size,
+                fetched: now,
+                requested: now,
+            });
+    }
+
+    // drops the filters nobody asked for within a ttl, and picks the ones worth refreshing
+    fn filters_to_refresh(&self) -> Vec<GameFilter> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, entry| entry.requested.elapsed() < self.ttl);
+
+        let mut filters = entries
+            .iter()
+            .map(|(filter, entry)| (entry.requested, filter.clone()))
+            .collect::<Vec<_>>();
+        filters.sort_by_key(|(requested, _)| std::cmp::Reverse(*requested));
+        filters
+            .into_iter()
+            .take(MAX_REFRESH_PER_PASS)
+            .map(|(_, filter)| filter)
+            .collect()
+    }
+}
+
+impl GiantBombClient {
+    // how long a catalog size is trusted before it's fetched again on the hot path
+    pub fn with_catalog_ttl(mut self, ttl: Duration) -> Self {
+        self.catalog_sizes = Arc::new(CatalogSizes {
+            ttl,
+            ..CatalogSizes::default()
+        });
+        self
+    }
+
+    // the number of games matching `filter`, from the cache while it's fresh
+    pub(super) async fn catalog_size(&self, filter: &GameFilter) -> Result<i64, Error> {
+        if let Some(size) = self.catalog_sizes.fresh(filter) {
+            return Ok(size);
+        }
+        self.refresh_catalog_size(filter).await
+    }
+
+    async fn refresh_catalog_size(&self, filter: &GameFilter) -> Result<i64, Error> {
+        let size = self.get_max_games(filter).await?;
+        self.catalog_sizes.insert(filter, size);
+        Ok(size)
+    }
+
+    // re-fetches the sizes asked for within the last ttl; run it more often than the ttl and
+    // the random game never waits on the count
+    #[tracing::instrument(name = "Refresh catalog sizes", skip(self))]
+    pub async fn refresh_catalog_sizes(&self) {
+        for filter in self.catalog_sizes.filters_to_refresh() {
+            if let Err(err) = self.refresh_catalog_size(&filter).await {
+                tracing::warn!("Failed to refresh catalog size for {:?}: {}", filter, err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::paged_envelope;
+    use crate::gb_client::{GameFilter, GiantBombClient};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn caches_sizes_per_filter_until_refreshed() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let size_response = |total: usize| paged_envelope(serde_json::json!([]), 0, total);
+        let _platform_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .and(query_param("filter", "platforms:94"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(size_response(300)))
+            .named("GET platform catalog size")
+            .expect(2)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _all_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(size_response(80000)))
+            .named("GET catalog size")
+            .expect(2)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+        let platform = GameFilter { platform: Some(94) };
+
+        // Act
+        let all = client.catalog_size(&GameFilter::default()).await.unwrap();
+        let on_platform = client.catalog_size(&platform).await.unwrap();
+        client.catalog_size(&GameFilter::default()).await.unwrap();
+        client.catalog_size(&platform).await.unwrap();
+        client.refresh_catalog_sizes().await;
+
+        // Assert
+        assert_eq!((all, on_platform), (80000, 300));
+    }
+
+    #[tokio::test]
+    async fn skips_empty_filters_and_stops_refreshing_idle_ones() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let size_response = |total: usize| paged_envelope(serde_json::json!([]), 0, total);
+        let _junk_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .and(query_param("filter", "platforms:999999"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(size_response(0)))
+            .named("GET junk catalog size")
+            .expect(2)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _platform_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .and(query_param("filter", "platforms:94"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(size_response(300)))
+            .named("GET platform catalog size")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .with_catalog_ttl(std::time::Duration::from_millis(50));
+        let junk = GameFilter {
+            platform: Some(999999),
+        };
+
+        // Act
+        client.catalog_size(&junk).await.unwrap();
+        client.catalog_size(&junk).await.unwrap();
+        client
+            .catalog_size(&GameFilter { platform: Some(94) })
+            .await
+            .unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        client.refresh_catalog_sizes().await;
+
+        // Assert
+        assert!(client.catalog_sizes.filters_to_refresh().is_empty());
+    }
+}
@@ -0,0 +1,189 @@
+use super::{resources, Characteristic, Envelope, Error, GameImage, GiantBombClient};
+use futures::future::try_join_all;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const CHARACTER_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "real_name",
+    "aliases",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "gender",
+    "birthday",
+    "first_appeared_in_game",
+    "franchises",
+    "games",
+];
+
+const PERSON_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "aliases",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "birth_date",
+    "death",
+    "country",
+    "hometown",
+    "first_credited_game",
+    "games",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Character {
+    id: i32,
+    guid: String,
+    name: String,
+    real_name: Option<String>,
+    aliases: Option<String>,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    // 0 = other, 1 = male, 2 = female
+    gender: Option<i32>,
+    birthday: Option<String>,
+    first_appeared_in_game: Option<Characteristic>,
+    franchises: Option<Vec<Characteristic>>,
+    games: Option<Vec<Characteristic>>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Person {
+    id: i32,
+    guid: String,
+    name: String,
+    aliases: Option<String>,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    birth_date: Option<String>,
+    death: Option<String>,
+    country: Option<String>,
+    hometown: Option<String>,
+    first_credited_game: Option<Characteristic>,
+    games: Option<Vec<Characteristic>>,
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Character details query", skip(self))]
+    pub async fn get_character(&self, id: i32) -> Result<Character, Error> {
+        let response: Envelope<Character> = self
+            .get_json(
+                &self.detail_url(resources::CHARACTER, id),
+                &[("field_list", CHARACTER_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+
+    #[tracing::instrument(name = "Person details query", skip(self))]
+    pub async fn get_person(&self, id: i32) -> Result<Person, Error> {
+        let response: Envelope<Person> = self
+            .get_json(
+                &self.detail_url(resources::PERSON, id),
+                &[("field_list", PERSON_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+
+    // games every one of the characters shows up in, in the first character's order
+    #[tracing::instrument(name = "Games with characters", skip(self))]
+    pub async fn get_games_with_characters(
+        &self,
+        character_ids: &[i32],
+    ) -> Result<Vec<Characteristic>, Error> {
+        let characters =
+            try_join_all(character_ids.iter().map(|id| self.get_character(*id))).await?;
+
+        let mut game_lists = characters
+            .into_iter()
+            .map(|character| character.games.unwrap_or_default());
+        let first = match game_lists.next() {
+            Some(games) => games,
+            None => return Ok(Vec::new()),
+        };
+        let others = game_lists
+            .map(|games| games.iter().map(|game| game.id).collect::<HashSet<_>>())
+            .collect::<Vec<_>>();
+
+        Ok(first
+            .into_iter()
+            .filter(|game| others.iter().all(|ids| ids.contains(&game.id)))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    fn character_response(id: i32, game_ids: &[i32]) -> serde_json::Value {
+        let games = game_ids
+            .iter()
+            .map(|id| {
+                serde_json::json!({
+                    "api_detail_url": format!("https://www.giantbomb.com/api/game/3030-{}/", id),
+                    "id": id,
+                    "name": format!("Game {}", id)
+                })
+            })
+            .collect::<Vec<_>>();
+        envelope(serde_json::json!({
+            "id": id,
+            "guid": format!("3005-{}", id),
+            "name": format!("Character {}", id),
+            "games": games
+        }))
+    }
+
+    #[tokio::test]
+    async fn intersects_character_game_lists() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _mario_guard = Mock::given(method("GET"))
+            .and(path("/api/character/3005-1/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(character_response(1, &[10, 20, 30, 40])),
+            )
+            .named("GET mario")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _luigi_guard = Mock::given(method("GET"))
+            .and(path("/api/character/3005-2/"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(character_response(2, &[40, 30, 50])),
+            )
+            .named("GET luigi")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let games = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_games_with_characters(&[1, 2])
+            .await
+            .unwrap();
+
+        // Assert
+        let ids = games.iter().map(|game| game.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![30, 40]);
+    }
+}
@@ -0,0 +1,156 @@
+use super::{resources, Characteristic, Envelope, Error, GameImage, GiantBombClient};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const COMPANY_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "abbreviation",
+    "aliases",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "date_founded",
+    "location_address",
+    "location_city",
+    "location_state",
+    "location_country",
+    "website",
+    "developed_games",
+    "published_games",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Company {
+    id: i32,
+    guid: String,
+    name: String,
+    abbreviation: Option<String>,
+    aliases: Option<String>,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    date_founded: Option<String>,
+    location_address: Option<String>,
+    location_city: Option<String>,
+    location_state: Option<String>,
+    location_country: Option<String>,
+    website: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    developed_games: Option<Vec<Characteristic>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    published_games: Option<Vec<Characteristic>>,
+}
+
+// a game from a company's portfolio and what the company did on it
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct CompanyGame {
+    #[serde(flatten)]
+    game: Characteristic,
+    developed: bool,
+    published: bool,
+}
+
+impl Company {
+    // pulls the developed and published games out of the company as a single list,
+    // developed games first, each game only once
+    pub fn take_games(&mut self) -> Vec<CompanyGame> {
+        let developed = self.developed_games.take().unwrap_or_default();
+        let published = self.published_games.take().unwrap_or_default();
+
+        let mut games: Vec<CompanyGame> = Vec::with_capacity(developed.len() + published.len());
+        let mut seen: HashMap<i32, usize> = HashMap::new();
+        for (game, is_developer) in developed
+            .into_iter()
+            .map(|g| (g, true))
+            .chain(published.into_iter().map(|g| (g, false)))
+        {
+            match seen.get(&game.id) {
+                Some(&idx) => {
+                    games[idx].developed |= is_developer;
+                    games[idx].published |= !is_developer;
+                }
+                None => {
+                    seen.insert(game.id, games.len());
+                    games.push(CompanyGame {
+                        game,
+                        developed: is_developer,
+                        published: !is_developer,
+                    });
+                }
+            }
+        }
+        games
+    }
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Company details query", skip(self))]
+    pub async fn get_company(&self, id: i32) -> Result<Company, Error> {
+        let response: Envelope<Company> = self
+            .get_json(
+                &self.detail_url(resources::COMPANY, id),
+                &[("field_list", COMPANY_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    fn game_ref(id: i32, name: &str) -> serde_json::Value {
+        serde_json::json!({
+            "api_detail_url": format!("https://www.giantbomb.com/api/game/3030-{}/", id),
+            "id": id,
+            "name": name,
+            "site_detail_url": format!("https://www.giantbomb.com/game/3030-{}/", id)
+        })
+    }
+
+    #[tokio::test]
+    async fn merges_developed_and_published_games() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let company_response = envelope(serde_json::json!({
+            "id": 90,
+            "guid": "3010-90",
+            "name": "Nintendo",
+            "date_founded": "1889-09-23 00:00:00",
+            "location_country": "Japan",
+            "developed_games": [game_ref(1, "Metroid"), game_ref(2, "Zelda")],
+            "published_games": [game_ref(2, "Zelda"), game_ref(3, "Pokemon Red")]
+        }));
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/company/3010-90/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(company_response))
+            .named("GET company details")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let mut company = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_company(90)
+            .await
+            .unwrap();
+        let games = company.take_games();
+
+        // Assert
+        assert_eq!(company.date_founded.as_deref(), Some("1889-09-23 00:00:00"));
+        assert_eq!(company.developed_games, None);
+        assert_eq!(games.len(), 3);
+        assert!(games[1].developed && games[1].published);
+        assert!(!games[2].developed && games[2].published);
+    }
+}
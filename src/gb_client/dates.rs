@@ -0,0 +1,130 @@
+use serde::{Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt;
+
+// GiantBomb dates are either exact (`1986-08-06 00:00:00`) or just expected
+// (a year, maybe a quarter or month, maybe a day), this covers both and sorts sensibly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReleaseDate {
+    pub year: i32,
+    pub month: Option<u32>,
+    pub day: Option<u32>,
+}
+
+impl ReleaseDate {
+    // parses the `YYYY-MM-DD` prefix of a GiantBomb date
+    pub fn parse(date: &str) -> Option<Self> {
+        let mut parts = date.get(..10)?.split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+
+        Some(ReleaseDate::checked(year, Some(month), Some(day)))
+    }
+
+    // drops a month that doesn't exist, and a day that isn't in its month (2021-02-31),
+    // so the date falls back to the precision that still holds
+    fn checked(year: i32, month: Option<u32>, day: Option<u32>) -> Self {
+        let month = month.filter(|m| (1..=12).contains(m));
+        let day = month
+            .zip(day)
+            .filter(|&(m, d)| d >= 1 && d <= days_in_month(year, m))
+            .map(|(_, d)| d);
+
+        ReleaseDate { year, month, day }
+    }
+
+    // the exact date when there is one, otherwise the `expected_release_*` fields; this is
+    // how both games and their releases carry dates
+    pub fn exact_or_expected(
+        exact: Option<&str>,
+        year: Option<i32>,
+        quarter: Option<i32>,
+        month: Option<i32>,
+        day: Option<i32>,
+    ) -> Option<Self> {
+        exact
+            .and_then(ReleaseDate::parse)
+            .or_else(|| ReleaseDate::expected(year, quarter, month, day))
+    }
+
+    // the `expected_release_*` fields; a quarter is pinned to its first month
+    pub fn expected(
+        year: Option<i32>,
+        quarter: Option<i32>,
+        month: Option<i32>,
+        day: Option<i32>,
+    ) -> Option<Self> {
+        let quarter = quarter.filter(|q| (1..=4).contains(q));
+        let month = month
+            .filter(|m| (1..=12).contains(m))
+            .or_else(|| quarter.map(|q| q * 3 - 2))
+            .map(|m| m as u32);
+        let day = day.and_then(|d| u32::try_from(d).ok());
+
+        Some(ReleaseDate::checked(year?, month, day))
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+            if let Some(day) = self.day {
+                write!(f, "-{:02}", day)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for ReleaseDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReleaseDate;
+
+    #[test]
+    fn parses_exact_and_expected_dates() {
+        let exact = ReleaseDate::parse("1986-08-06 00:00:00").unwrap();
+        let expected = ReleaseDate::expected(Some(2026), Some(3), None, None).unwrap();
+
+        assert_eq!(exact.to_string(), "1986-08-06");
+        assert_eq!(expected.to_string(), "2026-07");
+        assert_eq!(ReleaseDate::parse("soon"), None);
+        assert_eq!(ReleaseDate::expected(None, Some(1), None, None), None);
+        // GiantBomb has no fifth quarter, the year is all that's left
+        let bad_quarter = ReleaseDate::expected(Some(2026), Some(5), None, None).unwrap();
+        assert_eq!(bad_quarter.month, None);
+        assert_eq!(
+            ReleaseDate::expected(Some(2026), Some(0), None, Some(1)).map(|d| d.to_string()),
+            Some("2026".to_string())
+        );
+        // no such day, the month still holds
+        let bad_day = ReleaseDate::expected(Some(2021), None, Some(2), Some(31)).unwrap();
+        assert_eq!(bad_day.to_string(), "2021-02");
+        assert_eq!(
+            ReleaseDate::expected(Some(2024), None, Some(2), Some(29)).map(|d| d.to_string()),
+            Some("2024-02-29".to_string())
+        );
+        assert_eq!(
+            ReleaseDate::parse("2023-02-29 00:00:00").map(|d| d.to_string()),
+            Some("2023-02".to_string())
+        );
+        assert!(exact < expected);
+    }
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Deserializer};
+
+// GiantBomb isn't consistent about numbers, some come back as strings ("199.00")
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Number {
+    Int(i64),
+    Float(f64),
+    Text(String),
+}
+
+pub fn opt_i64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<i64>, D::Error> {
+    Ok(match Option::<Number>::deserialize(deserializer)? {
+        Some(Number::Int(n)) => Some(n),
+        Some(Number::Float(n)) => Some(n as i64),
+        Some(Number::Text(s)) => s.trim().replace(',', "").parse().ok(),
+        None => None,
+    })
+}
+
+pub fn opt_f64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<f64>, D::Error> {
+    Ok(match Option::<Number>::deserialize(deserializer)? {
+        Some(Number::Int(n)) => Some(n as f64),
+        Some(Number::Float(n)) => Some(n),
+        Some(Number::Text(s)) => s.trim().replace(',', "").parse().ok(),
+        None => None,
+    })
+}
@@ -0,0 +1,108 @@
+use super::{resources, Characteristic, Envelope, Error, GameImage, GiantBombClient, ReleaseDate};
+use serde::{Deserialize, Serialize};
+
+const DLC_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "game",
+    "platform",
+    "release_date",
+];
+
+// downloadable content, always tied to a parent game and a single platform
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Dlc {
+    id: i32,
+    guid: String,
+    name: String,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    game: Option<Characteristic>,
+    platform: Option<Characteristic>,
+    release_date: Option<String>,
+}
+
+impl Dlc {
+    pub fn date(&self) -> Option<ReleaseDate> {
+        self.release_date.as_deref().and_then(ReleaseDate::parse)
+    }
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "DLC details query", skip(self))]
+    pub async fn get_dlc(&self, id: i32) -> Result<Dlc, Error> {
+        let response: Envelope<Dlc> = self
+            .get_json(
+                &self.detail_url(resources::DLC, id),
+                &[("field_list", DLC_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+
+    // every DLC of a game, earliest first, undated ones at the end
+    #[tracing::instrument(name = "Game DLC query", skip(self))]
+    pub async fn get_dlcs_for_game(&self, game_id: i32) -> Result<Vec<Dlc>, Error> {
+        let mut dlcs: Vec<Dlc> = self
+            .get_all(
+                &self.list_url(resources::DLC),
+                &[
+                    ("filter", format!("game:{}", resources::GAME.guid(game_id))),
+                    ("field_list", DLC_FIELDS.join(",")),
+                ],
+            )
+            .await?;
+
+        dlcs.sort_by_key(|dlc| (dlc.date().is_none(), dlc.date()));
+        Ok(dlcs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn returns_game_dlcs_earliest_first() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let dlcs_response = envelope(serde_json::json!([
+            { "id": 1, "guid": "3020-1", "name": "Blood and Wine", "release_date": "2016-05-31 00:00:00" },
+            { "id": 2, "guid": "3020-2", "name": "Announced Pack" },
+            { "id": 3, "guid": "3020-3", "name": "Hearts of Stone", "release_date": "2015-10-13 00:00:00" }
+        ]));
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/dlcs/"))
+            .and(query_param("filter", "game:3030-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(dlcs_response))
+            .named("GET game dlcs")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let dlcs = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_dlcs_for_game(1)
+            .await
+            .unwrap();
+
+        // Assert
+        let names = dlcs.iter().map(|d| d.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec!["Hearts of Stone", "Blood and Wine", "Announced Pack"]
+        );
+    }
+}
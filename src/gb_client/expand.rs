@@ -0,0 +1,274 @@
+use super::cache::TtlCache;
+use super::{Characteristic, Error, Game, GiantBombClient};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+// platforms, companies and the like barely change, no need to ask again for a while
+const CACHE_TTL: Duration = Duration::from_secs(30 * 60);
+const CACHE_CAPACITY: usize = 2000;
+// how many references are fetched at once across all requests, see `with_expand_concurrency`
+pub const DEFAULT_EXPAND_CONCURRENCY: usize = 4;
+
+// the references of a game that can be swapped for the full objects
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ExpandField {
+    Platforms,
+    Developers,
+    Characters,
+    Concepts,
+    Themes,
+}
+
+impl ExpandField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExpandField::Platforms => "platforms",
+            ExpandField::Developers => "developers",
+            ExpandField::Characters => "characters",
+            ExpandField::Concepts => "concepts",
+            ExpandField::Themes => "themes",
+        }
+    }
+}
+
+impl fmt::Display for ExpandField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ExpandField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "platforms" => Ok(ExpandField::Platforms),
+            "developers" => Ok(ExpandField::Developers),
+            "characters" => Ok(ExpandField::Characters),
+            "concepts" => Ok(ExpandField::Concepts),
+            "themes" => Ok(ExpandField::Themes),
+            other => Err(format!("unknown expand field: {}", other)),
+        }
+    }
+}
+
+impl Game {
+    fn refs(&self, field: ExpandField) -> &[Characteristic] {
+        let refs = match field {
+            ExpandField::Platforms => &self.platforms,
+            ExpandField::Developers => &self.developers,
+            ExpandField::Characters => &self.characters,
+            ExpandField::Concepts => &self.concepts,
+            ExpandField::Themes => &self.themes,
+        };
+        refs.as_deref().unwrap_or_default()
+    }
+}
+
+// the part after `/api/` so the request goes to our api url, with our token, whatever
+// host the reference names
+fn api_path(api_detail_url: &str) -> Result<&str, Error> {
+    api_detail_url
+        .split_once("/api/")
+        .map(|(_, path)| path)
+        .filter(|path| !path.is_empty())
+        .ok_or_else(|| format!("not a GiantBomb api url: {}", api_detail_url).into())
+}
+
+// hydrated detail payloads keyed by their api path, shared by every clone of the client
+pub type ExpandCache = TtlCache<Arc<serde_json::Value>>;
+
+impl Default for ExpandCache {
+    fn default() -> Self {
+        TtlCache::new(CACHE_TTL, CACHE_CAPACITY)
+    }
+}
+
+// what a reference that couldn't be fetched turns into: the reference itself, flagged
+fn failed(reference: &Characteristic, err: &str) -> serde_json::Value {
+    let mut value = serde_json::to_value(reference).unwrap_or_default();
+    if let Some(object) = value.as_object_mut() {
+        object.insert("expand_error".to_string(), err.into());
+    }
+    value
+}
+
+impl GiantBombClient {
+    // caps the references fetched at once by every `expand_game` call together
+    pub fn with_expand_concurrency(mut self, concurrency: usize) -> Self {
+        self.expand_slots = Arc::new(tokio::sync::Semaphore::new(concurrency.max(1)));
+        self
+    }
+
+    async fn hydrate(&self, path: &str) -> Result<Arc<serde_json::Value>, Error> {
+        if let Some(value) = self.expanded.get(path) {
+            return Ok(value);
+        }
+
+        let _slot = self.expand_slots.acquire().await?;
+        let value = Arc::new(self.raw_get(path, &[]).await?.results);
+        self.expanded.insert(path, value.clone());
+        Ok(value)
+    }
+
+    // the full objects behind the game's references. a reference used by several fields is
+    // only fetched once; one that can't be fetched stays a reference with an `expand_error`
+    #[tracing::instrument(name = "Expand game", skip(self, game), fields(game_id = game.id))]
+    pub async fn expand_game(
+        &self,
+        game: &Game,
+        fields: &[ExpandField],
+    ) -> BTreeMap<&'static str, Vec<serde_json::Value>> {
+        let mut paths = Vec::new();
+        for field in fields {
+            for path in game
+                .refs(*field)
+                .iter()
+                .filter_map(|reference| api_path(&reference.api_detail_url).ok())
+            {
+                if !paths.contains(&path) {
+                    paths.push(path);
+                }
+            }
+        }
+
+        let hydrated = futures::future::join_all(paths.iter().map(|path| self.hydrate(path))).await;
+        let by_path = paths
+            .into_iter()
+            .zip(hydrated)
+            .map(|(path, result)| (path, result.map_err(|err| err.to_string())))
+            .collect::<HashMap<_, _>>();
+
+        let mut expanded = BTreeMap::new();
+        for field in fields {
+            let objects = game
+                .refs(*field)
+                .iter()
+                .map(|reference| {
+                    let hydrated = api_path(&reference.api_detail_url)
+                        .map_err(|err| err.to_string())
+                        .and_then(|path| by_path[path].clone());
+                    match hydrated {
+                        Ok(value) => value.as_ref().clone(),
+                        Err(err) => {
+                            tracing::warn!(
+                                "Failed to expand {}: {}",
+                                reference.api_detail_url,
+                                err
+                            );
+                            failed(reference, &err)
+                        }
+                    }
+                })
+                .collect();
+            expanded.insert(field.as_str(), objects);
+        }
+        expanded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExpandField;
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::{Game, GiantBombClient};
+    use wiremock::matchers::{method, path};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn hydrates_references_once_and_reuses_the_cache() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let reference = |path: &str, id: i32| {
+            serde_json::json!({
+                "api_detail_url": format!("https://www.giantbomb.com/api/{}", path),
+                "id": id,
+                "name": "Ref"
+            })
+        };
+        let game: Game = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "guid": "3030-1",
+            "name": "Metroid",
+            "platforms": [reference("platform/3045-21/", 21), reference("platform/3045-52/", 52)],
+            "developers": [reference("company/3010-90/", 90)]
+        }))
+        .unwrap();
+        for (detail_path, name) in &[
+            ("/api/platform/3045-21/", "NES"),
+            ("/api/platform/3045-52/", "Game Boy Advance"),
+            ("/api/company/3010-90/", "Nintendo"),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(*detail_path))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(envelope(serde_json::json!({ "name": name }))),
+                )
+                .expect(1)
+                .mount(&mock_gb_server)
+                .await;
+        }
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+        let fields = [ExpandField::Platforms, ExpandField::Developers];
+
+        // Act
+        let expanded = client.expand_game(&game, &fields).await;
+        let again = client.expand_game(&game, &fields).await;
+
+        // Assert
+        assert_eq!(expanded, again);
+        assert_eq!(expanded["platforms"][0]["name"], "NES");
+        assert_eq!(expanded["platforms"][1]["name"], "Game Boy Advance");
+        assert_eq!(expanded["developers"][0]["name"], "Nintendo");
+    }
+
+    #[tokio::test]
+    async fn flags_references_that_fail_and_keeps_the_rest() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let game: Game = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "guid": "3030-1",
+            "name": "Metroid",
+            "platforms": [
+                { "api_detail_url": "https://www.giantbomb.com/api/platform/3045-21/", "id": 21, "name": "NES" },
+                { "api_detail_url": "https://www.giantbomb.com/api/platform/3045-52/", "id": 52, "name": "GBA" },
+                { "api_detail_url": "https://www.giantbomb.com/platform/3045-94/", "id": 94, "name": "PC" }
+            ]
+        }))
+        .unwrap();
+        let _ok_guard = Mock::given(method("GET"))
+            .and(path("/api/platform/3045-21/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(envelope(
+                serde_json::json!({ "name": "NES", "abbreviation": "NES" }),
+            )))
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _failing_guard = Mock::given(method("GET"))
+            .and(path("/api/platform/3045-52/"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .with_expand_concurrency(1);
+
+        // Act
+        let expanded = client.expand_game(&game, &[ExpandField::Platforms]).await;
+
+        // Assert
+        let platforms = &expanded["platforms"];
+        assert_eq!(platforms.len(), 3);
+        assert_eq!(platforms[0]["abbreviation"], "NES");
+        assert_eq!(platforms[1]["id"], 52);
+        assert!(platforms[1]["expand_error"].is_string());
+        assert_eq!(platforms[2]["id"], 94);
+        assert!(platforms[2]["expand_error"].is_string());
+    }
+}
@@ -0,0 +1,188 @@
+use super::{
+    resources, Characteristic, Envelope, Error, Game, GameImage, GiantBombClient, ReleaseDate,
+};
+use serde::{Deserialize, Serialize};
+
+const FRANCHISE_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "aliases",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "games",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Franchise {
+    id: i32,
+    guid: String,
+    name: String,
+    aliases: Option<String>,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    games: Option<Vec<Characteristic>>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TimelineEntry {
+    release_date: Option<ReleaseDate>,
+    #[serde(flatten)]
+    game: Game,
+}
+
+// games of a single year, `year` is null for games without any release info
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TimelineYear {
+    year: Option<i32>,
+    games: Vec<TimelineEntry>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct FranchiseTimeline {
+    franchise: Franchise,
+    years: Vec<TimelineYear>,
+}
+
+// oldest first, undated games at the very end
+fn timeline(games: Vec<Game>) -> Vec<TimelineYear> {
+    let mut entries = games
+        .into_iter()
+        .map(|game| TimelineEntry {
+            release_date: game.release_date(),
+            game,
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|e| (e.release_date.is_none(), e.release_date));
+
+    let mut years: Vec<TimelineYear> = Vec::new();
+    for entry in entries {
+        let year = entry.release_date.map(|date| date.year);
+        match years.last_mut() {
+            Some(last) if last.year == year => last.games.push(entry),
+            _ => years.push(TimelineYear {
+                year,
+                games: vec![entry],
+            }),
+        }
+    }
+    years
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Franchise details query", skip(self))]
+    pub async fn get_franchise(&self, id: i32) -> Result<Franchise, Error> {
+        let response: Envelope<Franchise> = self
+            .get_json(
+                &self.detail_url(resources::FRANCHISE, id),
+                &[("field_list", FRANCHISE_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+
+    // every game of the franchise in release order, grouped by year
+    #[tracing::instrument(name = "Franchise timeline", skip(self))]
+    pub async fn get_franchise_timeline(&self, id: i32) -> Result<FranchiseTimeline, Error> {
+        let mut franchise = self.get_franchise(id).await?;
+        let ids = franchise
+            .games
+            .take()
+            .unwrap_or_default()
+            .iter()
+            .map(|game| game.id)
+            .collect::<Vec<_>>();
+
+        let games = self.get_games_by_ids(&ids).await?.into_games();
+
+        Ok(FranchiseTimeline {
+            franchise,
+            years: timeline(games),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn sorts_franchise_games_by_release_and_groups_by_year() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let game_ref = |id: i32| {
+            serde_json::json!({
+                "api_detail_url": format!("https://www.giantbomb.com/api/game/3030-{}/", id),
+                "id": id,
+                "name": "Metroid"
+            })
+        };
+        let franchise_response = envelope(serde_json::json!({
+            "id": 11,
+            "guid": "3025-11",
+            "name": "Metroid",
+            "games": [game_ref(1), game_ref(2), game_ref(3), game_ref(4), game_ref(5)]
+        }));
+        let games_response = envelope(serde_json::json!([
+            { "id": 1, "guid": "3030-1", "name": "Metroid Dread", "original_release_date": "2021-10-08" },
+            { "id": 2, "guid": "3030-2", "name": "Metroid Prime 4", "expected_release_year": 2025 },
+            { "id": 3, "guid": "3030-3", "name": "Metroid", "original_release_date": "1986-08-06" },
+            { "id": 4, "guid": "3030-4", "name": "Metroid Prime: Remastered", "original_release_date": "2021-02-08" },
+            { "id": 5, "guid": "3030-5", "name": "Metroid Prime 5" }
+        ]));
+        let _franchise_guard = Mock::given(method("GET"))
+            .and(path("/api/franchise/3025-11/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(franchise_response))
+            .named("GET franchise details")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _games_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .and(query_param("filter", "id:1|2|3|4|5"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(games_response))
+            .named("GET franchise games")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let timeline = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_franchise_timeline(11)
+            .await
+            .unwrap();
+
+        // Assert
+        let mut ids = timeline
+            .years
+            .iter()
+            .flat_map(|year| year.games.iter().map(|entry| entry.game.id))
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+        let years = timeline
+            .years
+            .iter()
+            .map(|year| (year.year, year.games.len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            years,
+            vec![(Some(1986), 1), (Some(2021), 2), (Some(2025), 1), (None, 1)]
+        );
+        assert_eq!(
+            timeline.years[1].games[0].game.name,
+            "Metroid Prime: Remastered"
+        );
+        assert_eq!(timeline.franchise.games, None);
+    }
+}
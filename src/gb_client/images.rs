@@ -0,0 +1,100 @@
+use super::{resources, Envelope, Error, GameImage, GiantBombClient, Page};
+use serde::{Deserialize, Serialize};
+
+// one image of an object's gallery, in every size GiantBomb renders
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct Image {
+    #[serde(flatten)]
+    sizes: GameImage,
+    // comma separated, e.g. `All Images,Screenshots`
+    image_tags: Option<String>,
+}
+
+impl GiantBombClient {
+    // the gallery of any object, `guid` being e.g. `3030-1` for a game. images have no
+    // type of their own, they always hang off the guid of whatever they picture
+    #[tracing::instrument(name = "Images query", skip(self))]
+    pub async fn get_images(
+        &self,
+        guid: &str,
+        tag: Option<&str>,
+        offset: i32,
+        limit: i32,
+    ) -> Result<Page<Image>, Error> {
+        let mut params = vec![("offset", offset.to_string()), ("limit", limit.to_string())];
+        if let Some(tag) = tag {
+            params.push(("filter", format!("image_tag:{}", tag)));
+        }
+
+        let response: Envelope<Vec<Image>> = self
+            .get_json(&self.resource_url(&format!("images/{}/", guid)), &params)
+            .await?;
+
+        Ok(response.into())
+    }
+
+    pub async fn get_game_images(
+        &self,
+        game_id: i32,
+        tag: Option<&str>,
+        offset: i32,
+        limit: i32,
+    ) -> Result<Page<Image>, Error> {
+        self.get_images(&resources::GAME.guid(game_id), tag, offset, limit)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::paged_envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn returns_a_page_of_tagged_game_images() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let images_response = paged_envelope(
+            serde_json::json!([{
+                "original_url": "original",
+                "super_url": "super",
+                "screen_url": "screen",
+                "screen_large_url": "screen_large",
+                "medium_url": "medium",
+                "small_url": "small",
+                "thumb_url": "thumb",
+                "icon_url": "icon",
+                "tiny_url": "tiny",
+                "image_tags": "All Images,Screenshots"
+            }]),
+            2,
+            3,
+        );
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/images/3030-1/"))
+            .and(query_param("filter", "image_tag:Screenshots"))
+            .and(query_param("offset", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(images_response))
+            .named("GET game images")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let page = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_game_images(1, Some("Screenshots"), 2, 2)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(page.total, 3);
+        assert_eq!(page.results[0].sizes.thumb_url.as_deref(), Some("thumb"));
+        assert_eq!(
+            page.results[0].image_tags.as_deref(),
+            Some("All Images,Screenshots")
+        );
+    }
+}
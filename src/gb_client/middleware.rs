@@ -0,0 +1,206 @@
+use reqwest::{Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next, Result};
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use task_local_extensions::Extensions;
+
+// first pause before a retry, doubled for every one after it
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+// a `Retry-After` longer than this isn't worth holding a request open for
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+// a request error with the token taken out, see `Redact`
+#[derive(Debug)]
+struct RedactedError(String);
+
+impl fmt::Display for RedactedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for RedactedError {}
+
+// reqwest errors carry the full url, api_key included. sits right under the tracing
+// middleware so the span's `error.message` never sees the token either
+pub struct Redact {
+    pub token: String,
+}
+
+#[async_trait::async_trait]
+impl Middleware for Redact {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        next.run(req, extensions).await.map_err(|err| {
+            let message = err.to_string();
+            if self.token.is_empty() || !message.contains(&self.token) {
+                return err;
+            }
+            reqwest_middleware::Error::middleware(RedactedError(
+                message.replace(&self.token, "REDACTED"),
+            ))
+        })
+    }
+}
+
+// connection failures, timeouts, 429s and 5xx are tried again with a growing pause;
+// whatever the last attempt got is what the caller sees
+pub struct Retry {
+    pub max_retries: u32,
+}
+
+impl Retry {
+    fn should_retry(outcome: &Result<Response>) -> bool {
+        match outcome {
+            Ok(response) => {
+                response.status() == StatusCode::TOO_MANY_REQUESTS
+                    || response.status().is_server_error()
+            }
+            Err(reqwest_middleware::Error::Reqwest(err)) => err.is_connect() || err.is_timeout(),
+            Err(_) => false,
+        }
+    }
+
+    // GiantBomb says how long to back off on a 429, in seconds
+    fn retry_after(outcome: &Result<Response>) -> Option<Duration> {
+        outcome
+            .as_ref()
+            .ok()?
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)?
+            .to_str()
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+            .map(Duration::from_secs)
+            .filter(|pause| *pause <= MAX_RETRY_AFTER)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for Retry {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        let mut attempt = 0;
+        loop {
+            // only bodies that can't be replayed fail to clone, our GETs have none
+            let retry = match req.try_clone() {
+                Some(retry) if attempt < self.max_retries => retry,
+                _ => return next.run(req, extensions).await,
+            };
+
+            let outcome = next.clone().run(retry, extensions).await;
+            if !Retry::should_retry(&outcome) {
+                return outcome;
+            }
+
+            let pause =
+                Retry::retry_after(&outcome).unwrap_or_else(|| RETRY_BACKOFF * 2u32.pow(attempt));
+            attempt += 1;
+            tracing::warn!(
+                "GiantBomb call failed, retry {} of {} in {:?}",
+                attempt,
+                self.max_retries,
+                pause
+            );
+            actix_web::rt::time::sleep(pause).await;
+        }
+    }
+}
+
+// token bucket: up to `burst` requests at once, then one more for every `interval`.
+// GiantBomb blocks clients that call it too fast, whatever the hourly limit says
+pub struct RateLimit {
+    burst: f64,
+    interval: Duration,
+    // tokens left and when they were last topped up
+    bucket: Mutex<(f64, Instant)>,
+}
+
+impl RateLimit {
+    pub fn new(burst: u32, interval: Duration) -> Self {
+        let burst = f64::from(burst.max(1));
+        RateLimit {
+            burst,
+            interval,
+            bucket: Mutex::new((burst, Instant::now())),
+        }
+    }
+
+    // takes a token when there's one, otherwise says how long until there is
+    fn try_acquire(&self) -> std::result::Result<(), Duration> {
+        let mut bucket = self.bucket.lock().unwrap();
+        let (tokens, refilled) = &mut *bucket;
+        let earned = refilled.elapsed().as_secs_f64() / self.interval.as_secs_f64();
+        *tokens = (*tokens + earned).min(self.burst);
+        *refilled = Instant::now();
+
+        if *tokens >= 1.0 {
+            *tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(self.interval.mul_f64(1.0 - *tokens))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RateLimit {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> Result<Response> {
+        while let Err(wait) = self.try_acquire() {
+            actix_web::rt::time::sleep(wait).await;
+        }
+        next.run(req, extensions).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RateLimit, Redact};
+    use reqwest_middleware::ClientBuilder;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn request_errors_never_carry_the_token() {
+        let client = ClientBuilder::new(reqwest::Client::new())
+            .with(Redact {
+                token: "secret_token".to_string(),
+            })
+            .build();
+
+        // nothing listens on port 1
+        let err = client
+            .get("http://127.0.0.1:1/api/games/?api_key=secret_token")
+            .send()
+            .await
+            .unwrap_err();
+
+        assert!(!err.to_string().contains("secret_token"));
+        assert!(err.to_string().contains("REDACTED"));
+    }
+
+    #[test]
+    fn allows_a_burst_then_makes_callers_wait() {
+        let limit = RateLimit::new(2, Duration::from_secs(10));
+
+        assert!(limit.try_acquire().is_ok());
+        assert!(limit.try_acquire().is_ok());
+        let wait = limit.try_acquire().unwrap_err();
+        assert!(wait > Duration::from_secs(9) && wait <= Duration::from_secs(10));
+    }
+}
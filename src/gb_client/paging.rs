@@ -0,0 +1,129 @@
+use super::{resources, Envelope, Error, Game, GameFilter, GiantBombClient, GAME_FIELDS};
+use futures::future;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+
+// how many pages beyond the one being consumed are already requested
+const PREFETCH_PAGES: usize = 1;
+
+// list endpoints page with `offset`, search with a 1-based `page`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Paging {
+    Offset,
+    Page,
+}
+
+impl Paging {
+    fn params(&self, index: usize, page_size: usize) -> [(&'static str, String); 2] {
+        let position = match self {
+            Paging::Offset => ("offset", (index * page_size).to_string()),
+            Paging::Page => ("page", (index + 1).to_string()),
+        };
+        [position, ("limit", page_size.to_string())]
+    }
+}
+
+impl GiantBombClient {
+    // every result of `url`, one page at a time. the first page tells us how many there are,
+    // after that the next page is already on its way while the current one is consumed
+    pub(super) fn paginate<T>(
+        &self,
+        url: String,
+        params: Vec<(&'static str, String)>,
+        paging: Paging,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<T, Error>> + 'static
+    where
+        T: DeserializeOwned + 'static,
+    {
+        let client = self.clone();
+        let fetch = move |index: usize| {
+            let client = client.clone();
+            let url = url.clone();
+            let mut params = params.clone();
+            params.extend(paging.params(index, page_size));
+            async move { client.get_json::<Vec<T>>(&url, &params).await }
+        };
+
+        stream::once(fetch(0))
+            .map_ok(move |first: Envelope<Vec<T>>| {
+                let total = first.number_of_total_results.max(0) as usize;
+                let pages = total.div_ceil(page_size);
+                let rest = stream::iter(1..pages)
+                    .map(fetch.clone())
+                    .buffered(PREFETCH_PAGES + 1)
+                    .map_ok(|page| page.results);
+
+                stream::once(future::ready(Ok(first.results))).chain(rest)
+            })
+            .try_flatten()
+            .map_ok(|results| stream::iter(results.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    // every game matching `filter`, `page_size` games per upstream request. only games and
+    // search (see `stream_search`) are streamed; the other lists back routes that serve one
+    // `Page` at a time, or are small enough to collect whole with `get_all`
+    pub fn stream_games(
+        &self,
+        filter: &GameFilter,
+        page_size: usize,
+    ) -> impl Stream<Item = Result<Game, Error>> + 'static {
+        let params = filter.apply(vec![
+            ("field_list", GAME_FIELDS.join(",")),
+            ("sort", "id:asc".to_string()),
+        ]);
+
+        self.paginate(
+            self.list_url(resources::GAME),
+            params,
+            Paging::Offset,
+            page_size.clamp(1, super::MAX_PAGE_SIZE),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::paged_envelope;
+    use crate::gb_client::{GameFilter, GiantBombClient};
+    use futures::TryStreamExt;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    fn page(offset: usize, ids: &[i32]) -> serde_json::Value {
+        let games = ids
+            .iter()
+            .map(|id| serde_json::json!({ "id": id, "guid": format!("3030-{}", id), "name": "Game" }))
+            .collect::<Vec<_>>();
+        paged_envelope(games.into(), offset, 5)
+    }
+
+    #[tokio::test]
+    async fn streams_every_page_of_games() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        for (offset, ids) in &[(0, vec![1, 2]), (2, vec![3, 4]), (4, vec![5])] {
+            Mock::given(method("GET"))
+                .and(path("/api/games/"))
+                .and(query_param("offset", offset.to_string()))
+                .and(query_param("limit", "2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(page(*offset, ids)))
+                .expect(1)
+                .mount(&mock_gb_server)
+                .await;
+        }
+
+        // Act
+        let games = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .stream_games(&GameFilter::default(), 2)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        // Assert
+        let ids = games.iter().map(|game| game.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+    }
+}
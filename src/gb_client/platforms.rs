@@ -0,0 +1,144 @@
+use super::{de, resources, Characteristic, Envelope, Error, GameImage, GiantBombClient, Page};
+use serde::{Deserialize, Serialize};
+
+const PLATFORM_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "abbreviation",
+    "aliases",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "install_base",
+    "original_price",
+    "release_date",
+    "online_support",
+    "company",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Platform {
+    id: i32,
+    guid: String,
+    name: String,
+    abbreviation: Option<String>,
+    aliases: Option<String>,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    // units sold
+    #[serde(default, deserialize_with = "de::opt_i64")]
+    install_base: Option<i64>,
+    // launch price in USD
+    #[serde(default, deserialize_with = "de::opt_f64")]
+    original_price: Option<f64>,
+    release_date: Option<String>,
+    online_support: Option<bool>,
+    company: Option<Characteristic>,
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Platform details query", skip(self))]
+    pub async fn get_platform(&self, id: i32) -> Result<Platform, Error> {
+        let response: Envelope<Platform> = self
+            .get_json(
+                &self.detail_url(resources::PLATFORM, id),
+                &[("field_list", PLATFORM_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+
+    #[tracing::instrument(name = "List platforms", skip(self))]
+    pub async fn list_platforms(&self, offset: i32, limit: i32) -> Result<Page<Platform>, Error> {
+        let response: Envelope<Vec<Platform>> = self
+            .get_json(
+                &self.list_url(resources::PLATFORM),
+                &[
+                    ("offset", offset.to_string()),
+                    ("limit", limit.to_string()),
+                    ("field_list", PLATFORM_FIELDS.join(",")),
+                    ("sort", "name:asc".to_string()),
+                ],
+            )
+            .await?;
+
+        Ok(response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::{GameFilter, GiantBombClient};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn parses_platform_hardware_metadata() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let platform_response = envelope(serde_json::json!({
+            "id": 21,
+            "guid": "3045-21",
+            "name": "Nintendo Entertainment System",
+            "abbreviation": "NES",
+            "install_base": "61910000",
+            "original_price": "199.00",
+            "release_date": "1985-10-18 00:00:00",
+            "online_support": false,
+            "company": {
+                "api_detail_url": "https://www.giantbomb.com/api/company/3010-90/",
+                "id": 90,
+                "name": "Nintendo"
+            }
+        }));
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/platform/3045-21/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(platform_response))
+            .named("GET platform details")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let platform = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_platform(21)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(platform.install_base, Some(61_910_000));
+        assert_eq!(platform.original_price, Some(199.0));
+        assert_eq!(platform.online_support, Some(false));
+        assert_eq!(platform.company.unwrap().name, "Nintendo");
+    }
+
+    #[tokio::test]
+    async fn random_game_is_filtered_by_platform() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let empty_response = envelope(serde_json::json!([]));
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .and(query_param("filter", "platforms:21"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(empty_response))
+            .named("GET max games")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let result = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_random_game_matching(&GameFilter { platform: Some(21) })
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,134 @@
+use super::{resources, Characteristic, Error, Game, GameImage, GiantBombClient};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+const GAME_RATING_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "image",
+    "api_detail_url",
+    "rating_board",
+];
+
+const RATING_BOARD_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "region",
+];
+
+// e.g. `ESRB: M` or `PEGI: 16+`
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct GameRating {
+    id: i32,
+    guid: String,
+    name: String,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    rating_board: Option<Characteristic>,
+}
+
+// e.g. ESRB, PEGI, CERO
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct RatingBoard {
+    id: i32,
+    guid: String,
+    name: String,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    region: Option<Characteristic>,
+}
+
+// `PEGI: 18+`, `pegi 18` and `PEGI:18` all end up as `pegi 18`
+fn rating_key(name: &str) -> String {
+    name.replace([':', '+'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// keeps games carrying any of the excluded ratings away from the random game and search,
+// games GiantBomb has no rating for always pass
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ContentFilter {
+    excluded: HashSet<String>,
+}
+
+impl ContentFilter {
+    pub fn new<S: AsRef<str>>(excluded: &[S]) -> Self {
+        ContentFilter {
+            excluded: excluded.iter().map(|r| rating_key(r.as_ref())).collect(),
+        }
+    }
+
+    pub fn allows(&self, game: &Game) -> bool {
+        self.allows_ratings(game.ratings())
+    }
+
+    // for things that only carry the rating names along, e.g. search suggestions
+    pub fn allows_ratings<'a>(&self, ratings: impl IntoIterator<Item = &'a str>) -> bool {
+        self.excluded.is_empty()
+            || !ratings
+                .into_iter()
+                .any(|rating| self.excluded.contains(&rating_key(rating)))
+    }
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "List game ratings", skip(self))]
+    pub async fn list_game_ratings(&self) -> Result<Vec<GameRating>, Error> {
+        self.get_all(
+            &self.list_url(resources::GAME_RATING),
+            &[("field_list", GAME_RATING_FIELDS.join(","))],
+        )
+        .await
+    }
+
+    #[tracing::instrument(name = "List rating boards", skip(self))]
+    pub async fn list_rating_boards(&self) -> Result<Vec<RatingBoard>, Error> {
+        self.get_all(
+            &self.list_url(resources::RATING_BOARD),
+            &[("field_list", RATING_BOARD_FIELDS.join(","))],
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ContentFilter;
+    use crate::gb_client::Game;
+
+    fn rated(ratings: &[&str]) -> Game {
+        let ratings = ratings
+            .iter()
+            .map(|name| serde_json::json!({ "api_detail_url": "", "id": 1, "name": name }))
+            .collect::<Vec<_>>();
+        serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "guid": "3030-1",
+            "name": "Game",
+            "original_game_rating": ratings
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn excludes_games_with_any_excluded_rating() {
+        let filter = ContentFilter::new(&["ESRB: M", "ESRB: AO", "PEGI 18"]);
+
+        assert!(!filter.allows(&rated(&["ESRB: M"])));
+        assert!(!filter.allows(&rated(&["ESRB: T", "PEGI: 18+"])));
+        assert!(filter.allows(&rated(&["ESRB: T", "PEGI: 12+"])));
+        assert!(filter.allows(&rated(&[])));
+        assert!(ContentFilter::default().allows(&rated(&["ESRB: AO"])));
+    }
+}
@@ -0,0 +1,175 @@
+use super::{resources, Characteristic, Error, GameImage, GiantBombClient, ReleaseDate};
+use serde::{Deserialize, Serialize};
+
+const RELEASE_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "game",
+    "platform",
+    "region",
+    "release_date",
+    "expected_release_year",
+    "expected_release_quarter",
+    "expected_release_month",
+    "expected_release_day",
+    "game_rating",
+    "product_code_type",
+    "product_code_value",
+    "minimum_players",
+    "maximum_players",
+];
+
+// a single regional release of a game on one platform
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Release {
+    id: i32,
+    guid: String,
+    name: String,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    game: Option<Characteristic>,
+    platform: Option<Characteristic>,
+    region: Option<Characteristic>,
+    release_date: Option<String>,
+    expected_release_year: Option<i32>,
+    expected_release_quarter: Option<i32>,
+    expected_release_month: Option<i32>,
+    expected_release_day: Option<i32>,
+    game_rating: Option<Characteristic>,
+    product_code_type: Option<String>,
+    product_code_value: Option<String>,
+    minimum_players: Option<i32>,
+    maximum_players: Option<i32>,
+}
+
+impl Release {
+    // when it came out in its region, or failing that when it's expected to
+    pub fn date(&self) -> Option<ReleaseDate> {
+        ReleaseDate::exact_or_expected(
+            self.release_date.as_deref(),
+            self.expected_release_year,
+            self.expected_release_quarter,
+            self.expected_release_month,
+            self.expected_release_day,
+        )
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PlatformReleases {
+    platform: Option<String>,
+    releases: Vec<Release>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RegionReleases {
+    region: Option<String>,
+    platforms: Vec<PlatformReleases>,
+}
+
+// region -> platform -> releases, every level ordered by its earliest release
+pub fn group_by_region(mut releases: Vec<Release>) -> Vec<RegionReleases> {
+    releases.sort_by_key(|r| (r.date().is_none(), r.date()));
+
+    let name = |c: &Option<Characteristic>| c.as_ref().map(|c| c.name.clone());
+    let mut regions: Vec<RegionReleases> = Vec::new();
+    for release in releases {
+        let region = name(&release.region);
+        let platform = name(&release.platform);
+
+        let region_idx = match regions.iter().position(|r| r.region == region) {
+            Some(idx) => idx,
+            None => {
+                regions.push(RegionReleases {
+                    region,
+                    platforms: Vec::new(),
+                });
+                regions.len() - 1
+            }
+        };
+
+        let platforms = &mut regions[region_idx].platforms;
+        match platforms.iter_mut().find(|p| p.platform == platform) {
+            Some(group) => group.releases.push(release),
+            None => platforms.push(PlatformReleases {
+                platform,
+                releases: vec![release],
+            }),
+        }
+    }
+    regions
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Game releases query", skip(self))]
+    pub async fn get_releases_for_game(&self, game_id: i32) -> Result<Vec<Release>, Error> {
+        self.get_all(
+            &self.list_url(resources::RELEASE),
+            &[
+                ("filter", format!("game:{}", resources::GAME.guid(game_id))),
+                ("field_list", RELEASE_FIELDS.join(",")),
+            ],
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn groups_releases_by_region_and_platform() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let reference = |id: i32, name: &str| serde_json::json!({ "api_detail_url": "", "id": id, "name": name });
+        let releases_response = envelope(serde_json::json!([
+            { "id": 1, "guid": "3050-1", "name": "Metroid (US)", "release_date": "1987-08-15 00:00:00",
+              "region": reference(1, "United States"), "platform": reference(21, "Nintendo Entertainment System") },
+            { "id": 2, "guid": "3050-2", "name": "Metroid (JP)", "release_date": "1986-08-06 00:00:00",
+              "region": reference(6, "Japan"), "platform": reference(88, "Famicom Disk System") },
+            { "id": 3, "guid": "3050-3", "name": "Metroid (EU)", "release_date": "1988-01-15 00:00:00",
+              "region": reference(2, "United Kingdom"), "platform": reference(21, "Nintendo Entertainment System"),
+              "product_code_value": "NES-MT-EEC" }
+        ]));
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/releases/"))
+            .and(query_param("filter", "game:3030-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(releases_response))
+            .named("GET releases")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let releases = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_releases_for_game(1)
+            .await
+            .unwrap();
+        let regions = super::group_by_region(releases);
+
+        // Assert
+        let names = regions
+            .iter()
+            .map(|r| r.region.as_deref().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["Japan", "United States", "United Kingdom"]);
+        assert_eq!(
+            regions[2].platforms[0].releases[0]
+                .product_code_value
+                .as_deref(),
+            Some("NES-MT-EEC")
+        );
+    }
+}
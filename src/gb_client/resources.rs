@@ -0,0 +1,169 @@
+// GiantBomb names every resource twice (`game` for details, `games` for lists)
+// and prefixes every guid with a type id, e.g. `3030-1` is the game with id 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resource {
+    pub detail: &'static str,
+    pub list: &'static str,
+    pub type_id: u32,
+}
+
+impl Resource {
+    pub fn guid(&self, id: i32) -> String {
+        format!("{}-{}", self.type_id, id)
+    }
+}
+
+pub const GAME: Resource = Resource {
+    detail: "game",
+    list: "games",
+    type_id: 3030,
+};
+
+pub const PLATFORM: Resource = Resource {
+    detail: "platform",
+    list: "platforms",
+    type_id: 3045,
+};
+
+pub const COMPANY: Resource = Resource {
+    detail: "company",
+    list: "companies",
+    type_id: 3010,
+};
+
+pub const FRANCHISE: Resource = Resource {
+    detail: "franchise",
+    list: "franchises",
+    type_id: 3025,
+};
+
+pub const CHARACTER: Resource = Resource {
+    detail: "character",
+    list: "characters",
+    type_id: 3005,
+};
+
+pub const PERSON: Resource = Resource {
+    detail: "person",
+    list: "people",
+    type_id: 3040,
+};
+
+pub const RELEASE: Resource = Resource {
+    detail: "release",
+    list: "releases",
+    type_id: 3050,
+};
+
+pub const REVIEW: Resource = Resource {
+    detail: "review",
+    list: "reviews",
+    type_id: 1900,
+};
+
+pub const USER_REVIEW: Resource = Resource {
+    detail: "user_review",
+    list: "user_reviews",
+    type_id: 2200,
+};
+
+pub const VIDEO: Resource = Resource {
+    detail: "video",
+    list: "videos",
+    type_id: 2300,
+};
+
+pub const VIDEO_SHOW: Resource = Resource {
+    detail: "video_show",
+    list: "video_shows",
+    type_id: 2340,
+};
+
+pub const VIDEO_CATEGORY: Resource = Resource {
+    detail: "video_category",
+    list: "video_categories",
+    type_id: 2320,
+};
+
+pub const DLC: Resource = Resource {
+    detail: "dlc",
+    list: "dlcs",
+    type_id: 3020,
+};
+
+pub const GENRE: Resource = Resource {
+    detail: "genre",
+    list: "genres",
+    type_id: 3060,
+};
+
+pub const THEME: Resource = Resource {
+    detail: "theme",
+    list: "themes",
+    type_id: 3032,
+};
+
+pub const CONCEPT: Resource = Resource {
+    detail: "concept",
+    list: "concepts",
+    type_id: 3015,
+};
+
+pub const LOCATION: Resource = Resource {
+    detail: "location",
+    list: "locations",
+    type_id: 3035,
+};
+
+pub const OBJECT: Resource = Resource {
+    detail: "object",
+    list: "objects",
+    type_id: 3055,
+};
+
+pub const GAME_RATING: Resource = Resource {
+    detail: "game_rating",
+    list: "game_ratings",
+    type_id: 3065,
+};
+
+pub const RATING_BOARD: Resource = Resource {
+    detail: "rating_board",
+    list: "rating_boards",
+    type_id: 3070,
+};
+
+// everything above, checked against GiantBomb's `/types` at startup
+pub const ALL: [Resource; 20] = [
+    GAME,
+    PLATFORM,
+    COMPANY,
+    FRANCHISE,
+    CHARACTER,
+    PERSON,
+    RELEASE,
+    REVIEW,
+    USER_REVIEW,
+    VIDEO,
+    VIDEO_SHOW,
+    VIDEO_CATEGORY,
+    DLC,
+    GENRE,
+    THEME,
+    CONCEPT,
+    LOCATION,
+    OBJECT,
+    GAME_RATING,
+    RATING_BOARD,
+];
+
+// `3030-1` style, anything else would end up in the upstream path as is
+pub fn is_guid(guid: &str) -> bool {
+    match guid.split_once('-') {
+        Some((type_id, id)) => {
+            let digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+            digits(type_id) && digits(id)
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,182 @@
+use super::{resources, Characteristic, Error, GiantBombClient};
+use serde::{Deserialize, Serialize};
+
+const REVIEW_FIELDS: &[&str] = &[
+    "deck",
+    "dlc_name",
+    "game",
+    "platforms",
+    "publish_date",
+    "release",
+    "reviewer",
+    "score",
+    "site_detail_url",
+    "api_detail_url",
+];
+
+const USER_REVIEW_FIELDS: &[&str] = &[
+    "deck",
+    "date_added",
+    "game",
+    "reviewer",
+    "score",
+    "site_detail_url",
+    "api_detail_url",
+];
+
+// a GiantBomb staff review, scored out of 5
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Review {
+    deck: Option<String>,
+    dlc_name: Option<String>,
+    game: Option<Characteristic>,
+    platforms: Option<String>,
+    publish_date: Option<String>,
+    release: Option<Characteristic>,
+    reviewer: Option<String>,
+    score: Option<i32>,
+    site_detail_url: Option<String>,
+    api_detail_url: Option<String>,
+}
+
+// a review from a GiantBomb user, also scored out of 5
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct UserReview {
+    deck: Option<String>,
+    date_added: Option<String>,
+    game: Option<Characteristic>,
+    reviewer: Option<String>,
+    score: Option<i32>,
+    site_detail_url: Option<String>,
+    api_detail_url: Option<String>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ReviewSummary {
+    // mean of the staff review scores, usually there's only one
+    staff_score: Option<f64>,
+    staff_count: usize,
+    user_mean: Option<f64>,
+    user_count: usize,
+    // how many user reviews gave 1, 2, 3, 4 and 5 stars
+    distribution: [usize; 5],
+}
+
+fn mean(scores: &[i32]) -> Option<f64> {
+    if scores.is_empty() {
+        None
+    } else {
+        Some(scores.iter().sum::<i32>() as f64 / scores.len() as f64)
+    }
+}
+
+impl ReviewSummary {
+    pub fn new(reviews: &[Review], user_reviews: &[UserReview]) -> Self {
+        let staff_scores = reviews.iter().filter_map(|r| r.score).collect::<Vec<_>>();
+        let user_scores = user_reviews
+            .iter()
+            .filter_map(|r| r.score)
+            .filter(|score| (1..=5).contains(score))
+            .collect::<Vec<_>>();
+
+        let mut distribution = [0; 5];
+        for score in &user_scores {
+            distribution[*score as usize - 1] += 1;
+        }
+
+        ReviewSummary {
+            staff_score: mean(&staff_scores),
+            staff_count: staff_scores.len(),
+            user_mean: mean(&user_scores),
+            user_count: user_scores.len(),
+            distribution,
+        }
+    }
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Game reviews query", skip(self))]
+    pub async fn get_reviews_for_game(&self, game_id: i32) -> Result<Vec<Review>, Error> {
+        self.get_all(
+            &self.list_url(resources::REVIEW),
+            &[
+                ("filter", format!("game:{}", resources::GAME.guid(game_id))),
+                ("field_list", REVIEW_FIELDS.join(",")),
+            ],
+        )
+        .await
+    }
+
+    #[tracing::instrument(name = "Game user reviews query", skip(self))]
+    pub async fn get_user_reviews_for_game(&self, game_id: i32) -> Result<Vec<UserReview>, Error> {
+        self.get_all(
+            &self.list_url(resources::USER_REVIEW),
+            &[
+                (
+                    "filter",
+                    format!("object:{}", resources::GAME.guid(game_id)),
+                ),
+                ("field_list", USER_REVIEW_FIELDS.join(",")),
+            ],
+        )
+        .await
+    }
+
+    pub async fn get_review_summary(&self, game_id: i32) -> Result<ReviewSummary, Error> {
+        let (reviews, user_reviews) = futures::try_join!(
+            self.get_reviews_for_game(game_id),
+            self.get_user_reviews_for_game(game_id)
+        )?;
+
+        Ok(ReviewSummary::new(&reviews, &user_reviews))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn aggregates_staff_and_user_scores() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _reviews_guard = Mock::given(method("GET"))
+            .and(path("/api/reviews/"))
+            .and(query_param("filter", "game:3030-1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(envelope(serde_json::json!([{ "score": 4 }]))),
+            )
+            .named("GET reviews")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _user_reviews_guard = Mock::given(method("GET"))
+            .and(path("/api/user_reviews/"))
+            .and(query_param("filter", "object:3030-1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(envelope(
+                serde_json::json!([{ "score": 5 }, { "score": 5 }, { "score": 2 }]),
+            )))
+            .named("GET user reviews")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let summary = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_review_summary(1)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(summary.staff_score, Some(4.0));
+        assert_eq!(summary.staff_count, 1);
+        assert_eq!(summary.user_mean, Some(4.0));
+        assert_eq!(summary.user_count, 3);
+        assert_eq!(summary.distribution, [0, 1, 0, 0, 2]);
+    }
+}
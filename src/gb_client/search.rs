@@ -0,0 +1,197 @@
+use super::paging::Paging;
+use super::{
+    Character, Company, Error, Franchise, Game, GiantBombClient, Person, Platform, Video,
+    GAME_FIELDS,
+};
+use futures::stream::{Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+// how many hits we ask for when searching across several resources at once
+const MULTI_SEARCH_LIMIT: usize = 10;
+
+// GiantBomb's search pages hold 10 results at most
+const MAX_SEARCH_PAGE_SIZE: usize = 10;
+
+// fields the non-game resources need on top of the game ones
+const EXTRA_SEARCH_FIELDS: &[&str] = &[
+    "resource_type",
+    "api_detail_url",
+    "abbreviation",
+    "aliases",
+    "real_name",
+    "publish_date",
+    "length_seconds",
+    "low_url",
+    "high_url",
+    "hd_url",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceType {
+    Game,
+    Franchise,
+    Character,
+    Company,
+    Person,
+    Platform,
+    Video,
+}
+
+impl ResourceType {
+    pub const ALL: [ResourceType; 7] = [
+        ResourceType::Game,
+        ResourceType::Franchise,
+        ResourceType::Character,
+        ResourceType::Company,
+        ResourceType::Person,
+        ResourceType::Platform,
+        ResourceType::Video,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ResourceType::Game => "game",
+            ResourceType::Franchise => "franchise",
+            ResourceType::Character => "character",
+            ResourceType::Company => "company",
+            ResourceType::Person => "person",
+            ResourceType::Platform => "platform",
+            ResourceType::Video => "video",
+        }
+    }
+}
+
+impl fmt::Display for ResourceType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ResourceType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "game" => Ok(ResourceType::Game),
+            "franchise" => Ok(ResourceType::Franchise),
+            "character" => Ok(ResourceType::Character),
+            "company" => Ok(ResourceType::Company),
+            "person" => Ok(ResourceType::Person),
+            "platform" => Ok(ResourceType::Platform),
+            "video" => Ok(ResourceType::Video),
+            other => Err(format!("unknown resource type: {}", other)),
+        }
+    }
+}
+
+// a single search result, GiantBomb tells us what it is through `resource_type`
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+#[serde(tag = "resource_type", rename_all = "snake_case")]
+pub enum SearchHit {
+    Game(Game),
+    Franchise(Franchise),
+    Character(Character),
+    Company(Company),
+    Person(Person),
+    Platform(Platform),
+    Video(Video),
+    // anything we don't model yet (concepts, objects, ...) is kept as a marker only
+    #[serde(other)]
+    Other,
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Multi resource search", skip(self))]
+    pub async fn search(
+        &self,
+        query: &str,
+        resources: &[ResourceType],
+    ) -> Result<Vec<SearchHit>, Error> {
+        self.stream_search(query, resources, MULTI_SEARCH_LIMIT)
+            .take(MULTI_SEARCH_LIMIT)
+            .try_collect()
+            .await
+    }
+
+    // every hit of a search, page after page, `page_size` hits per upstream request
+    pub fn stream_search(
+        &self,
+        query: &str,
+        resources: &[ResourceType],
+        page_size: usize,
+    ) -> impl Stream<Item = Result<SearchHit, Error>> + 'static {
+        let resources = resources
+            .iter()
+            .map(ResourceType::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+        let fields = GAME_FIELDS
+            .iter()
+            .chain(EXTRA_SEARCH_FIELDS)
+            .copied()
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.paginate(
+            self.resource_url("search"),
+            vec![
+                ("resources", resources),
+                ("field_list", fields),
+                ("query", query.to_string()),
+            ],
+            Paging::Page,
+            page_size.clamp(1, MAX_SEARCH_PAGE_SIZE),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::{GiantBombClient, ResourceType, SearchHit};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[test]
+    fn parses_resource_types() {
+        assert_eq!("franchise".parse(), Ok(ResourceType::Franchise));
+        assert_eq!(" person".parse(), Ok(ResourceType::Person));
+        assert!("pizza".parse::<ResourceType>().is_err());
+    }
+
+    #[tokio::test]
+    async fn returns_hits_discriminated_by_resource_type() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let search_response = envelope(serde_json::json!([
+            { "resource_type": "game", "id": 1, "guid": "3030-1", "name": "Metroid" },
+            { "resource_type": "franchise", "id": 2, "guid": "3025-2", "name": "Metroid" },
+            { "resource_type": "promo", "id": 3, "guid": "1700-3", "name": "Quick Look" }
+        ]));
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/search"))
+            .and(query_param("resources", "game,franchise"))
+            .and(query_param("query", "metroid"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(search_response))
+            .named("GET search")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let result = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .search("metroid", &[ResourceType::Game, ResourceType::Franchise])
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 3);
+        assert!(matches!(&result[0], SearchHit::Game(_)));
+        assert!(matches!(&result[1], SearchHit::Franchise(_)));
+        assert_eq!(result[2], SearchHit::Other);
+    }
+}
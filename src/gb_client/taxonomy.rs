@@ -0,0 +1,206 @@
+use super::{resources, Characteristic, Envelope, Error, Game, GameImage, GiantBombClient, Page};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+const TAXON_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "aliases",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+];
+
+// the ways GiantBomb tags games beyond platforms and companies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaxonomyKind {
+    Genre,
+    Theme,
+    Concept,
+    Location,
+    Object,
+}
+
+impl TaxonomyKind {
+    fn resource(&self) -> resources::Resource {
+        match self {
+            TaxonomyKind::Genre => resources::GENRE,
+            TaxonomyKind::Theme => resources::THEME,
+            TaxonomyKind::Concept => resources::CONCEPT,
+            TaxonomyKind::Location => resources::LOCATION,
+            TaxonomyKind::Object => resources::OBJECT,
+        }
+    }
+
+    // genres and themes only know their name, GiantBomb doesn't link them back to games
+    pub fn has_games(&self) -> bool {
+        !matches!(self, TaxonomyKind::Genre | TaxonomyKind::Theme)
+    }
+}
+
+impl fmt::Display for TaxonomyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.resource().list)
+    }
+}
+
+// the plural names, same as the GiantBomb list endpoints
+impl FromStr for TaxonomyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "genres" => Ok(TaxonomyKind::Genre),
+            "themes" => Ok(TaxonomyKind::Theme),
+            "concepts" => Ok(TaxonomyKind::Concept),
+            "locations" => Ok(TaxonomyKind::Location),
+            "objects" => Ok(TaxonomyKind::Object),
+            other => Err(format!("unknown taxonomy: {}", other)),
+        }
+    }
+}
+
+// a single genre, theme, concept, location or object
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Taxon {
+    id: i32,
+    guid: String,
+    name: String,
+    aliases: Option<String>,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+}
+
+// just enough of a taxon to find its games
+#[derive(Deserialize)]
+struct TaxonGames {
+    games: Option<Vec<Characteristic>>,
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "List taxonomy", skip(self))]
+    pub async fn list_taxonomy(
+        &self,
+        kind: TaxonomyKind,
+        offset: i32,
+        limit: i32,
+    ) -> Result<Page<Taxon>, Error> {
+        let response: Envelope<Vec<Taxon>> = self
+            .get_json(
+                &self.list_url(kind.resource()),
+                &[
+                    ("offset", offset.to_string()),
+                    ("limit", limit.to_string()),
+                    ("field_list", TAXON_FIELDS.join(",")),
+                    ("sort", "name:asc".to_string()),
+                ],
+            )
+            .await?;
+
+        Ok(response.into())
+    }
+
+    #[tracing::instrument(name = "Taxon details query", skip(self))]
+    pub async fn get_taxon(&self, kind: TaxonomyKind, id: i32) -> Result<Taxon, Error> {
+        let response: Envelope<Taxon> = self
+            .get_json(
+                &self.detail_url(kind.resource(), id),
+                &[("field_list", TAXON_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+
+    // the taxon only lists game refs, so we page through those and fetch the games of one page
+    #[tracing::instrument(name = "Taxon games query", skip(self))]
+    pub async fn get_taxon_games(
+        &self,
+        kind: TaxonomyKind,
+        id: i32,
+        offset: i32,
+        limit: i32,
+    ) -> Result<Page<Game>, Error> {
+        let taxon: Envelope<TaxonGames> = self
+            .get_json(
+                &self.detail_url(kind.resource(), id),
+                &[("field_list", "games".to_string())],
+            )
+            .await?;
+        let refs = Page::from_vec(taxon.results.games.unwrap_or_default(), offset, limit);
+        let ids = refs.results.iter().map(|game| game.id).collect::<Vec<_>>();
+
+        Ok(Page {
+            offset: refs.offset,
+            limit: refs.limit,
+            total: refs.total,
+            results: self.get_games_by_ids(&ids).await?.into_games(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TaxonomyKind;
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[test]
+    fn parses_taxonomy_kinds() {
+        assert_eq!("concepts".parse(), Ok(TaxonomyKind::Concept));
+        assert_eq!(TaxonomyKind::Object.to_string(), "objects");
+        assert!("genre".parse::<TaxonomyKind>().is_err());
+        assert!(!TaxonomyKind::Theme.has_games());
+    }
+
+    #[tokio::test]
+    async fn pages_through_the_games_of_a_concept() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let game_ref =
+            |id: i32| serde_json::json!({ "api_detail_url": "", "id": id, "name": "Game" });
+        let _concept_guard = Mock::given(method("GET"))
+            .and(path("/api/concept/3015-7/"))
+            .and(query_param("field_list", "games"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                    "games": [game_ref(1), game_ref(2), game_ref(3)]
+                }))),
+            )
+            .named("GET concept games")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _games_guard = Mock::given(method("GET"))
+            .and(path("/api/games/"))
+            .and(query_param("filter", "id:2|3"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([
+                    { "id": 2, "guid": "3030-2", "name": "Two" },
+                    { "id": 3, "guid": "3030-3", "name": "Three" }
+                ]))),
+            )
+            .named("GET games")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let page = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_taxon_games(TaxonomyKind::Concept, 7, 1, 2)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(page.total, 3);
+        assert_eq!(page.results.len(), 2);
+    }
+}
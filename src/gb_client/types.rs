@@ -0,0 +1,213 @@
+use super::{resources, Error, GiantBombClient};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+// errors are flattened to strings so the shared future's output can be cloned
+pub(super) type PendingTypes = Shared<BoxFuture<'static, Result<Arc<Vec<TypeInfo>>, String>>>;
+
+// one entry of GiantBomb's `/types`, e.g. `game`/`games` is 3030
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TypeInfo {
+    #[serde(rename = "id")]
+    pub type_id: u32,
+    pub detail_resource_name: String,
+    pub list_resource_name: String,
+}
+
+impl TypeInfo {
+    fn matches(&self, name: &str) -> bool {
+        self.detail_resource_name == name || self.list_resource_name == name
+    }
+}
+
+// the hardcoded resources that don't line up with what GiantBomb says, as log friendly messages
+fn mismatches(types: &[TypeInfo]) -> Vec<String> {
+    resources::ALL
+        .iter()
+        .filter_map(
+            |resource| match types.iter().find(|t| t.matches(resource.detail)) {
+                None => Some(format!("{} is not a known type", resource.detail)),
+                Some(t)
+                    if t.type_id != resource.type_id || t.list_resource_name != resource.list =>
+                {
+                    Some(format!(
+                        "{} is {}/{} but GiantBomb says {}/{}",
+                        resource.detail,
+                        resource.list,
+                        resource.type_id,
+                        t.list_resource_name,
+                        t.type_id
+                    ))
+                }
+                Some(_) => None,
+            },
+        )
+        .collect()
+}
+
+impl GiantBombClient {
+    // fetches `/types` once and keeps it for the lifetime of the client, later calls are free.
+    // every caller shares the first fetch; a failed one is forgotten so the next call retries
+    pub async fn types(&self) -> Result<Arc<Vec<TypeInfo>>, Error> {
+        let pending = self
+            .types
+            .lock()
+            .unwrap()
+            .get_or_insert_with(|| {
+                let client = self.clone();
+                async move {
+                    client
+                        .get_json::<Vec<TypeInfo>>(&client.resource_url("types/"), &[])
+                        .await
+                        .map(|response| Arc::new(response.results))
+                        .map_err(|err| err.to_string())
+                }
+                .boxed()
+                .shared()
+            })
+            .clone();
+
+        let types = pending.await;
+        if types.is_err() {
+            let mut slot = self.types.lock().unwrap();
+            if slot
+                .as_ref()
+                .is_some_and(|p| p.peek().is_some_and(Result::is_err))
+            {
+                *slot = None;
+            }
+        }
+        Ok(types?)
+    }
+
+    // loads `/types` and warns about every hardcoded resource GiantBomb disagrees with
+    #[tracing::instrument(name = "Load types", skip(self))]
+    pub async fn load_types(&self) -> Result<Vec<String>, Error> {
+        let types = self.types().await?;
+        let mismatches = mismatches(&types);
+        for mismatch in &mismatches {
+            tracing::warn!("resource mismatch: {}", mismatch);
+        }
+        tracing::info!("loaded {} resource types", types.len());
+        Ok(mismatches)
+    }
+
+    // by detail or list name, e.g. `accessory` or `accessories`
+    pub async fn find_type(&self, name: &str) -> Result<Option<TypeInfo>, Error> {
+        Ok(self
+            .types()
+            .await?
+            .iter()
+            .find(|t| t.matches(name))
+            .cloned())
+    }
+
+    // escape hatch for resources we don't model yet, the detail payload as is
+    #[tracing::instrument(name = "Untyped resource query", skip(self))]
+    pub async fn get_resource(&self, name: &str, id: i32) -> Result<serde_json::Value, Error> {
+        let info = self
+            .find_type(name)
+            .await?
+            .ok_or_else(|| format!("unknown resource type: {}", name))?;
+
+        let response = self
+            .raw_get(
+                &format!("{}/{}-{}/", info.detail_resource_name, info.type_id, id),
+                &[],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn validates_resources_and_fetches_untyped_ones_once() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let types_response = envelope(serde_json::json!([
+            { "detail_resource_name": "accessory", "id": 3000, "list_resource_name": "accessories" },
+            { "detail_resource_name": "game", "id": 3030, "list_resource_name": "games" },
+            { "detail_resource_name": "platform", "id": 3046, "list_resource_name": "platforms" }
+        ]));
+        let _types_guard = Mock::given(method("GET"))
+            .and(path("/api/types/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(types_response))
+            .named("GET types")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _accessory_guard = Mock::given(method("GET"))
+            .and(path("/api/accessory/3000-5/"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(envelope(
+                serde_json::json!({ "id": 5, "name": "Power Glove" }),
+            )))
+            .named("GET accessory")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+
+        // Act
+        let mismatches = client.load_types().await.unwrap();
+        let accessory = client.get_resource("accessories", 5).await.unwrap();
+        let unknown = client.get_resource("pizza", 1).await;
+
+        // Assert
+        assert!(mismatches.iter().all(|m| !m.starts_with("game ")));
+        assert!(mismatches
+            .contains(&"platform is platforms/3045 but GiantBomb says platforms/3046".to_string()));
+        assert!(mismatches.contains(&"dlc is not a known type".to_string()));
+        assert_eq!(accessory["name"], "Power Glove");
+        assert!(unknown.is_err());
+    }
+
+    #[tokio::test]
+    async fn concurrent_lookups_share_one_load_and_failures_are_retried() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _failing_guard = Mock::given(method("GET"))
+            .and(path("/api/types/"))
+            .respond_with(ResponseTemplate::new(500))
+            .named("GET types failing")
+            .up_to_n_times(1)
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _types_guard = Mock::given(method("GET"))
+            .and(path("/api/types/"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(envelope(serde_json::json!([
+                        { "detail_resource_name": "game", "id": 3030, "list_resource_name": "games" }
+                    ])))
+                    .set_delay(std::time::Duration::from_millis(100)),
+            )
+            .named("GET types")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let client = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+
+        // Act
+        let failed = client.types().await;
+        let (by_detail, by_list) =
+            futures::join!(client.find_type("game"), client.find_type("games"));
+        let cached = client.find_type("game").await;
+
+        // Assert
+        assert!(failed.is_err());
+        assert_eq!(by_detail.unwrap().unwrap().type_id, 3030);
+        assert_eq!(by_list.unwrap().unwrap().type_id, 3030);
+        assert_eq!(cached.unwrap().unwrap().type_id, 3030);
+    }
+}
@@ -0,0 +1,326 @@
+use super::{resources, Characteristic, Envelope, Error, GameImage, GiantBombClient, Page};
+use serde::{Deserialize, Serialize};
+
+const VIDEO_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "name",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "publish_date",
+    "length_seconds",
+    "user",
+    "video_type",
+    "video_show",
+    "video_categories",
+    "youtube_id",
+    "low_url",
+    "high_url",
+    "hd_url",
+];
+
+const VIDEO_SHOW_FIELDS: &[&str] = &[
+    "id",
+    "guid",
+    "title",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+    "position",
+    "active",
+];
+
+const VIDEO_CATEGORY_FIELDS: &[&str] = &[
+    "id",
+    "name",
+    "deck",
+    "image",
+    "api_detail_url",
+    "site_detail_url",
+];
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoQuality {
+    Low,
+    High,
+    Hd,
+}
+
+// GiantBomb serves every video in a few encodings; the urls only play with an api_key attached
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct VideoUrls {
+    low_url: Option<String>,
+    high_url: Option<String>,
+    hd_url: Option<String>,
+}
+
+impl VideoUrls {
+    pub fn get(&self, quality: VideoQuality) -> Option<&str> {
+        match quality {
+            VideoQuality::Low => self.low_url.as_deref(),
+            VideoQuality::High => self.high_url.as_deref(),
+            VideoQuality::Hd => self.hd_url.as_deref(),
+        }
+    }
+
+    // the best encoding available, hd first
+    pub fn best(&self) -> Option<(VideoQuality, &str)> {
+        [VideoQuality::Hd, VideoQuality::High, VideoQuality::Low]
+            .iter()
+            .find_map(|quality| self.get(*quality).map(|url| (*quality, url)))
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Video {
+    id: i32,
+    guid: String,
+    name: String,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    publish_date: Option<String>,
+    length_seconds: Option<i64>,
+    user: Option<String>,
+    video_type: Option<String>,
+    video_show: Option<Characteristic>,
+    video_categories: Option<Vec<Characteristic>>,
+    youtube_id: Option<String>,
+    #[serde(flatten)]
+    urls: VideoUrls,
+}
+
+impl Video {
+    pub fn urls(&self) -> &VideoUrls {
+        &self.urls
+    }
+
+    fn in_category(&self, category_id: i32) -> bool {
+        self.video_categories
+            .iter()
+            .flatten()
+            .any(|category| category.id == category_id)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct VideoShow {
+    id: i32,
+    guid: String,
+    title: String,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+    position: Option<i32>,
+    active: Option<bool>,
+}
+
+// e.g. Quick Looks, Reviews, Trailers
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct VideoCategory {
+    id: i32,
+    name: String,
+    deck: Option<String>,
+    image: Option<GameImage>,
+    api_detail_url: Option<String>,
+    site_detail_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VideoFilter {
+    pub show: Option<i32>,
+    pub category: Option<i32>,
+}
+
+impl VideoFilter {
+    fn to_param(&self) -> Option<String> {
+        let filters = self
+            .show
+            .iter()
+            .map(|id| format!("video_show:{}", id))
+            .chain(
+                self.category
+                    .iter()
+                    .map(|id| format!("video_categories:{}", id)),
+            )
+            .collect::<Vec<_>>();
+
+        if filters.is_empty() {
+            None
+        } else {
+            Some(filters.join(","))
+        }
+    }
+}
+
+// just enough of a game to find its videos
+#[derive(Deserialize)]
+struct GameVideos {
+    videos: Option<Vec<Characteristic>>,
+}
+
+impl GiantBombClient {
+    #[tracing::instrument(name = "Video details query", skip(self))]
+    pub async fn get_video(&self, id: i32) -> Result<Video, Error> {
+        let response: Envelope<Video> = self
+            .get_json(
+                &self.detail_url(resources::VIDEO, id),
+                &[("field_list", VIDEO_FIELDS.join(","))],
+            )
+            .await?;
+
+        Ok(response.results)
+    }
+
+    #[tracing::instrument(name = "List videos", skip(self))]
+    pub async fn list_videos(
+        &self,
+        filter: &VideoFilter,
+        offset: i32,
+        limit: i32,
+    ) -> Result<Page<Video>, Error> {
+        let mut params = vec![
+            ("offset", offset.to_string()),
+            ("limit", limit.to_string()),
+            ("field_list", VIDEO_FIELDS.join(",")),
+            ("sort", "publish_date:desc".to_string()),
+        ];
+        if let Some(filter) = filter.to_param() {
+            params.push(("filter", filter));
+        }
+
+        let response: Envelope<Vec<Video>> = self
+            .get_json(&self.list_url(resources::VIDEO), &params)
+            .await?;
+
+        Ok(response.into())
+    }
+
+    // videos GiantBomb links to the game, newest first, optionally only one category
+    #[tracing::instrument(name = "Game videos query", skip(self))]
+    pub async fn get_videos_for_game(
+        &self,
+        game_id: i32,
+        category: Option<i32>,
+    ) -> Result<Vec<Video>, Error> {
+        let game: Envelope<GameVideos> = self
+            .get_json(
+                &self.detail_url(resources::GAME, game_id),
+                &[("field_list", "videos".to_string())],
+            )
+            .await?;
+        let ids = game
+            .results
+            .videos
+            .unwrap_or_default()
+            .iter()
+            .map(|video| video.id)
+            .collect::<Vec<_>>();
+
+        let mut videos: Vec<Video> = self
+            .fetch_by_ids(resources::VIDEO, &ids, VIDEO_FIELDS)
+            .await?;
+        if let Some(category) = category {
+            videos.retain(|video| video.in_category(category));
+        }
+        // publish dates are `YYYY-MM-DD HH:MM:SS` so they sort as strings
+        videos.sort_by(|a, b| b.publish_date.cmp(&a.publish_date));
+        Ok(videos)
+    }
+
+    #[tracing::instrument(name = "List video shows", skip(self))]
+    pub async fn list_video_shows(
+        &self,
+        offset: i32,
+        limit: i32,
+    ) -> Result<Page<VideoShow>, Error> {
+        let response: Envelope<Vec<VideoShow>> = self
+            .get_json(
+                &self.list_url(resources::VIDEO_SHOW),
+                &[
+                    ("offset", offset.to_string()),
+                    ("limit", limit.to_string()),
+                    ("field_list", VIDEO_SHOW_FIELDS.join(",")),
+                ],
+            )
+            .await?;
+
+        Ok(response.into())
+    }
+
+    #[tracing::instrument(name = "List video categories", skip(self))]
+    pub async fn list_video_categories(&self) -> Result<Vec<VideoCategory>, Error> {
+        self.get_all(
+            &self.list_url(resources::VIDEO_CATEGORY),
+            &[("field_list", VIDEO_CATEGORY_FIELDS.join(","))],
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VideoQuality;
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::GiantBombClient;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    #[tokio::test]
+    async fn returns_game_videos_in_a_category_newest_first() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let quick_look =
+            serde_json::json!([{ "api_detail_url": "", "id": 3, "name": "Quick Looks" }]);
+        let _game_guard = Mock::given(method("GET"))
+            .and(path("/api/game/3030-1/"))
+            .and(query_param("field_list", "videos"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                    "videos": [
+                        { "api_detail_url": "", "id": 10, "name": "Old Quick Look" },
+                        { "api_detail_url": "", "id": 11, "name": "Trailer" },
+                        { "api_detail_url": "", "id": 12, "name": "New Quick Look" }
+                    ]
+                }))),
+            )
+            .named("GET game videos")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let _videos_guard = Mock::given(method("GET"))
+            .and(path("/api/videos/"))
+            .and(query_param("filter", "id:10|11|12"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([
+                { "id": 10, "guid": "2300-10", "name": "Old Quick Look", "publish_date": "2014-01-01 10:00:00",
+                  "video_categories": quick_look, "low_url": "low", "high_url": "high" },
+                { "id": 11, "guid": "2300-11", "name": "Trailer", "publish_date": "2020-01-01 10:00:00" },
+                { "id": 12, "guid": "2300-12", "name": "New Quick Look", "publish_date": "2019-01-01 10:00:00",
+                  "video_categories": quick_look, "low_url": "low", "high_url": "high", "hd_url": "hd" }
+            ]))))
+            .named("GET videos")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+
+        // Act
+        let videos = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri())
+            .get_videos_for_game(1, Some(3))
+            .await
+            .unwrap();
+
+        // Assert
+        let names = videos.iter().map(|v| v.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, vec!["New Quick Look", "Old Quick Look"]);
+        assert_eq!(videos[0].urls().best(), Some((VideoQuality::Hd, "hd")));
+        assert_eq!(videos[1].urls().best(), Some((VideoQuality::High, "high")));
+        assert_eq!(videos[1].urls().get(VideoQuality::Low), Some("low"));
+    }
+}
@@ -1,18 +1,46 @@
 mod gb_client;
+mod pool;
+mod routes;
+mod search;
+mod settings;
+
+pub use settings::Settings;
 
 use actix_web::dev::Server;
 use actix_web::{web, App, HttpRequest, HttpResponse, HttpServer, Responder};
 use serde::{Deserialize, Serialize};
 use std::net::TcpListener;
+use std::time::Duration;
+use tracing::Instrument;
 use tracing_actix_web::TracingLogger;
 
+// how often the random game is re-rolled when the content filter rejects it
+const MAX_RANDOM_ATTEMPTS: usize = 5;
+
 struct AppContext {
-    gb_token: String,
+    gb: gb_client::GiantBombClient,
+    content_filter: gb_client::ContentFilter,
+    random_pool: pool::RandomPool,
+    suggestions: search::suggest::SuggestCache,
+    dictionary: search::spelling::Dictionary,
 }
 
 #[derive(Deserialize)]
 struct SearchRequest {
     query: String,
+    // comma separated list of resource types, e.g. `game,franchise`
+    resources: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RandomGameRequest {
+    // only pick from games released on this platform
+    platform: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct SuggestRequest {
+    q: String,
 }
 
 #[derive(Serialize)]
@@ -23,17 +51,47 @@ struct GameResponse {
 
 #[derive(Serialize)]
 struct SearchResponse {
-    games: Vec<gb_client::Game>,
+    games: Vec<search::rank::RankedGame>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    results: Option<Vec<gb_client::SearchHit>>,
+    // "did you mean" corrections, only filled in when nothing matched
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<String>,
     message: String,
 }
 
-async fn random_game(ctx: web::Data<AppContext>) -> impl Responder {
-    let token = &ctx.gb_token;
-    match gb_client::get_random_game(token).await {
-        Ok(game) => HttpResponse::Ok().json(GameResponse {
-            game: Some(game),
-            message: "OK".to_string(),
-        }),
+#[derive(Serialize)]
+struct SuggestResponse {
+    suggestions: Vec<search::suggest::Suggestion>,
+    message: String,
+}
+
+async fn random_game(
+    params: web::Query<RandomGameRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let filter = gb_client::GameFilter {
+        platform: params.platform,
+    };
+    // the pool only holds games from the whole catalog
+    let pooled = if filter == gb_client::GameFilter::default() {
+        ctx.random_pool.pop()
+    } else {
+        None
+    };
+    let game = match pooled {
+        Some(game) => Ok(game),
+        None => random_allowed_game(&ctx, &filter).await,
+    };
+
+    match game {
+        Ok(game) => {
+            ctx.dictionary.insert(&game.name);
+            HttpResponse::Ok().json(GameResponse {
+                game: Some(game),
+                message: "OK".to_string(),
+            })
+        }
         Err(err) => {
             tracing::error!("Error fetching game: {}", err);
             HttpResponse::BadGateway().json(GameResponse {
@@ -44,18 +102,84 @@ async fn random_game(ctx: web::Data<AppContext>) -> impl Responder {
     }
 }
 
+async fn random_allowed_game(
+    ctx: &AppContext,
+    filter: &gb_client::GameFilter,
+) -> Result<gb_client::Game, gb_client::Error> {
+    for _ in 0..MAX_RANDOM_ATTEMPTS {
+        let game = ctx.gb.get_random_game_matching(filter).await?;
+        if ctx.content_filter.allows(&game) {
+            return Ok(game);
+        }
+        tracing::info!("skipping {} because of its rating", game.name);
+    }
+    Err(format!("no allowed game after {} attempts", MAX_RANDOM_ATTEMPTS).into())
+}
+
+// tops the random pool up one game at a time, slow enough to stay within GiantBomb's rate limit
+async fn keep_pool_warm(ctx: web::Data<AppContext>, every: Duration) {
+    loop {
+        // one game per pause; a filtered out one is simply tried again next time
+        if !ctx.random_pool.is_full() {
+            match ctx.gb.get_random_game().await {
+                Ok(game) if ctx.content_filter.allows(&game) => {
+                    ctx.random_pool.push(game);
+                    tracing::info!("random pool at {} games", ctx.random_pool.depth());
+                }
+                Ok(game) => tracing::info!("skipping {} because of its rating", game.name),
+                Err(err) => tracing::warn!("Failed to refill the random pool: {}", err),
+            }
+        }
+        actix_web::rt::time::sleep(every).await;
+    }
+}
+
+// keeps the game counts behind the random game fresh, so requests never wait on them
+async fn keep_catalog_sizes_fresh(gb: gb_client::GiantBombClient, every: Duration) {
+    loop {
+        actix_web::rt::time::sleep(every).await;
+        gb.refresh_catalog_sizes().await;
+    }
+}
+
 async fn search_game_name(
     params: web::Query<SearchRequest>,
     ctx: web::Data<AppContext>,
 ) -> impl Responder {
-    let token = &ctx.gb_token;
-    let search_term = &params.query;
+    // GiantBomb (and everything we compare against locally) only ever sees the normalized query
+    let normalized = search::normalize::normalize(&params.query);
+    let span = tracing::info_span!(
+        "Search",
+        query = %params.query,
+        normalized_query = %normalized
+    );
+    search_games(&ctx, &normalized, params.resources.as_deref())
+        .instrument(span)
+        .await
+}
+
+async fn search_games(
+    ctx: &AppContext,
+    search_term: &str,
+    resources: Option<&str>,
+) -> HttpResponse {
+    if let Some(resources) = resources {
+        return search_resources(ctx, search_term, resources).await;
+    }
+
     tracing::info!("searching for game with name: {}", search_term);
-    match gb_client::search_by_game_name(token, search_term).await {
-        Ok(results) => HttpResponse::Ok().json(SearchResponse {
-            games: results,
-            message: "OK".to_string(),
-        }),
+    match ctx.gb.search_by_game_name(search_term).await {
+        Ok(mut results) => {
+            results.retain(|game| ctx.content_filter.allows(game));
+            ctx.dictionary
+                .extend(results.iter().map(|game| game.name.as_str()));
+            HttpResponse::Ok().json(SearchResponse {
+                suggestions: did_you_mean(ctx, search_term, results.is_empty()),
+                games: search::rank::rank(search_term, results),
+                results: None,
+                message: "OK".to_string(),
+            })
+        }
         Err(err) => {
             tracing::error!("Error searching for game: {}", err);
             HttpResponse::BadGateway().json(GameResponse {
@@ -66,24 +190,189 @@ async fn search_game_name(
     }
 }
 
+fn did_you_mean(ctx: &AppContext, search_term: &str, no_results: bool) -> Vec<String> {
+    if !no_results {
+        return Vec::new();
+    }
+
+    let suggestions = ctx.dictionary.suggest(search_term);
+    tracing::info!(
+        "no results for {}, suggesting {:?}",
+        search_term,
+        suggestions
+    );
+    suggestions
+}
+
+async fn search_resources(ctx: &AppContext, search_term: &str, resources: &str) -> HttpResponse {
+    let resources = match resources
+        .split(',')
+        .filter(|r| !r.trim().is_empty())
+        .map(|r| r.parse::<gb_client::ResourceType>())
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(resources) if !resources.is_empty() => resources,
+        Ok(_) => gb_client::ResourceType::ALL.to_vec(),
+        Err(err) => {
+            return HttpResponse::BadRequest().json(SearchResponse {
+                games: Vec::new(),
+                results: None,
+                suggestions: Vec::new(),
+                message: err,
+            })
+        }
+    };
+
+    tracing::info!("searching {:?} for: {}", resources, search_term);
+    match ctx.gb.search(search_term, &resources).await {
+        Ok(mut hits) => {
+            hits.retain(|hit| match hit {
+                gb_client::SearchHit::Game(game) => ctx.content_filter.allows(game),
+                _ => true,
+            });
+            let games = hits
+                .iter()
+                .filter_map(|hit| match hit {
+                    gb_client::SearchHit::Game(game) => Some(game.clone()),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            ctx.dictionary
+                .extend(games.iter().map(|game| game.name.as_str()));
+            HttpResponse::Ok().json(SearchResponse {
+                suggestions: did_you_mean(ctx, search_term, hits.is_empty()),
+                games: search::rank::rank(search_term, games),
+                results: Some(hits),
+                message: "OK".to_string(),
+            })
+        }
+        Err(err) => {
+            tracing::error!("Error searching resources: {}", err);
+            HttpResponse::BadGateway().json(SearchResponse {
+                games: Vec::new(),
+                results: None,
+                suggestions: Vec::new(),
+                message: "Failed to search".to_string(),
+            })
+        }
+    }
+}
+
+async fn suggest(params: web::Query<SuggestRequest>, ctx: web::Data<AppContext>) -> impl Responder {
+    let prefix = match search::suggest::Prefix::parse(&params.q) {
+        Ok(prefix) => prefix,
+        Err(message) => {
+            return HttpResponse::BadRequest().json(SuggestResponse {
+                suggestions: Vec::new(),
+                message,
+            })
+        }
+    };
+
+    match ctx.suggestions.suggest(&ctx.gb, &prefix).await {
+        Ok(mut suggestions) => {
+            suggestions.retain(|s| ctx.content_filter.allows_ratings(s.ratings()));
+            ctx.dictionary
+                .extend(suggestions.iter().map(|s| s.name.as_str()));
+            HttpResponse::Ok().json(SuggestResponse {
+                suggestions,
+                message: "OK".to_string(),
+            })
+        }
+        Err(err) => {
+            tracing::error!("Error fetching suggestions: {}", err);
+            HttpResponse::BadGateway().json(SuggestResponse {
+                suggestions: Vec::new(),
+                message: "Failed to get suggestions".to_string(),
+            })
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct StatsResponse {
+    random_pool: pool::PoolStats,
+}
+
+async fn stats(ctx: web::Data<AppContext>) -> impl Responder {
+    HttpResponse::Ok().json(StatsResponse {
+        random_pool: ctx.random_pool.stats(),
+    })
+}
+
+// actix's HttpResponse is itself a future, which clippy mistakes for a forgotten .await
+#[allow(clippy::async_yields_async)]
 #[tracing::instrument(name = "Ping handler", skip(_req))]
 async fn ping(_req: HttpRequest) -> impl Responder {
     HttpResponse::NoContent()
 }
 
-pub fn srv(listener: TcpListener, gb_token: &str) -> Result<Server, std::io::Error> {
-    // since we want a string, we put the borrow into a Box on the heap
-    // and move that into the closure;
-    let token = Box::new(gb_token.to_string());
+pub fn srv(listener: TcpListener, settings: Settings) -> Result<Server, std::io::Error> {
+    // built once so every worker shares the same client (and its connection pool)
+    let ctx = web::Data::new(AppContext {
+        gb: gb_client::GiantBombClient::new(&settings.gb_token)
+            .with_catalog_ttl(Duration::from_secs(settings.catalog_ttl_secs))
+            .with_max_body_bytes(settings.max_body_bytes)
+            .with_expand_concurrency(settings.expand_concurrency)
+            .with_raw_cache_ttl(Duration::from_secs(settings.raw_cache_ttl_secs))
+            .with_retries(settings.max_retries)
+            .with_rate_limit(
+                settings.rate_limit_burst,
+                Duration::from_millis(settings.rate_limit_interval_ms),
+            ),
+        content_filter: gb_client::ContentFilter::new(&settings.excluded_ratings),
+        random_pool: pool::RandomPool::new(settings.random_pool_size),
+        suggestions: search::suggest::SuggestCache::default(),
+        dictionary: search::spelling::Dictionary::default(),
+    });
+
+    if let Some(path) = &settings.dictionary_file {
+        match ctx.dictionary.load(path) {
+            Ok(count) => tracing::info!("spelling dictionary seeded with {} names", count),
+            Err(err) => tracing::warn!("Failed to load {}: {}", path, err),
+        }
+    }
+
+    // a failure here only costs the validation, `/types` is fetched again on first use
+    if settings.validate_types {
+        let gb = ctx.gb.clone();
+        actix_web::rt::spawn(async move {
+            if let Err(err) = gb.load_types().await {
+                tracing::warn!("Failed to load GiantBomb types: {}", err);
+            }
+        });
+    }
+
+    actix_web::rt::spawn(keep_catalog_sizes_fresh(
+        ctx.gb.clone(),
+        Duration::from_secs(settings.catalog_ttl_secs / 2).max(Duration::from_secs(1)),
+    ));
+
+    if settings.random_pool_size > 0 {
+        actix_web::rt::spawn(keep_pool_warm(
+            ctx.clone(),
+            Duration::from_secs(settings.random_pool_refill_secs).max(Duration::from_secs(1)),
+        ));
+    }
+
     let srv = HttpServer::new(move || {
         App::new()
             .wrap(TracingLogger::default())
-            .app_data(web::Data::new(AppContext {
-                gb_token: token.to_string(),
-            }))
+            .app_data(ctx.clone())
             .route("/_ping", web::get().to(ping))
+            .route("/_stats", web::get().to(stats))
             .route("/games/random", web::get().to(random_game))
             .route("/search", web::get().to(search_game_name))
+            .route("/search/suggest", web::get().to(suggest))
+            .configure(routes::characters::configure)
+            .configure(routes::companies::configure)
+            .configure(routes::franchises::configure)
+            .configure(routes::games::configure)
+            .configure(routes::images::configure)
+            .configure(routes::platforms::configure)
+            .configure(routes::ratings::configure)
+            .configure(routes::taxonomy::configure)
+            .configure(routes::videos::configure)
     })
     .listen(listener)?
     .run();
@@ -39,11 +39,11 @@ async fn main() -> std::io::Result<()> {
 
     set_global_default(subscriber).expect("Failed to set subscriber");
 
-    let gb_token = env::var("GB_TOKEN").expect("GB_TOKEN env is required");
+    let settings = giantbomb_rs::Settings::from_env();
     let address = addr();
     let listener = TcpListener::bind(address)
-        .expect(format!("Failed to bind to address: {:?}", address).as_ref());
-    giantbomb_rs::srv(listener, &gb_token)?.await?;
+        .unwrap_or_else(|_| panic!("Failed to bind to address: {:?}", address));
+    giantbomb_rs::srv(listener, settings)?.await?;
 
     global::shutdown_tracer_provider();
 
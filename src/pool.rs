@@ -0,0 +1,98 @@
+use crate::gb_client::Game;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+// random games fetched ahead of time so `/games/random` doesn't wait on GiantBomb
+pub struct RandomPool {
+    games: Mutex<VecDeque<Game>>,
+    capacity: usize,
+    // requests served from the pool and ones that had to go live
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct PoolStats {
+    depth: usize,
+    capacity: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl RandomPool {
+    pub fn new(capacity: usize) -> Self {
+        RandomPool {
+            games: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.games.lock().unwrap().len()
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.depth() >= self.capacity
+    }
+
+    // oldest first, so a game never sits in the pool for long
+    pub fn pop(&self) -> Option<Game> {
+        let game = self.games.lock().unwrap().pop_front();
+        let counter = if game.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        game
+    }
+
+    // drops the game when the pool is already full
+    pub fn push(&self, game: Game) {
+        let mut games = self.games.lock().unwrap();
+        if games.len() < self.capacity {
+            games.push_back(game);
+        }
+    }
+
+    pub fn stats(&self) -> PoolStats {
+        PoolStats {
+            depth: self.depth(),
+            capacity: self.capacity,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RandomPool;
+    use crate::gb_client::Game;
+
+    fn game(id: i32) -> Game {
+        let mut game = Game::default();
+        game.id = id;
+        game
+    }
+
+    #[test]
+    fn hands_out_games_in_order_and_counts_hits_and_misses() {
+        let pool = RandomPool::new(2);
+        pool.push(game(1));
+        pool.push(game(2));
+        pool.push(game(3));
+
+        assert!(pool.is_full());
+        assert_eq!(pool.pop().map(|g| g.id), Some(1));
+        assert_eq!(pool.pop().map(|g| g.id), Some(2));
+        assert_eq!(pool.pop(), None);
+
+        let stats = pool.stats();
+        assert_eq!((stats.depth, stats.hits, stats.misses), (0, 2, 1));
+    }
+}
@@ -0,0 +1,34 @@
+// handlers for the GiantBomb resources beyond the random game and search
+pub mod characters;
+pub mod companies;
+pub mod franchises;
+pub mod games;
+pub mod images;
+pub mod platforms;
+pub mod ratings;
+pub mod taxonomy;
+pub mod videos;
+
+use serde::Deserialize;
+
+const DEFAULT_PAGE_SIZE: i32 = 20;
+// GiantBomb refuses anything bigger than this
+const MAX_PAGE_SIZE: i32 = 100;
+
+#[derive(Deserialize)]
+pub struct PageRequest {
+    offset: Option<i32>,
+    limit: Option<i32>,
+}
+
+impl PageRequest {
+    pub fn offset(&self) -> i32 {
+        self.offset.unwrap_or(0).max(0)
+    }
+
+    pub fn limit(&self) -> i32 {
+        self.limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE)
+    }
+}
@@ -0,0 +1,109 @@
+use crate::gb_client::{Character, Characteristic, Person};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+
+// each character is a separate upstream call, so keep the fan out small
+const MAX_CHARACTERS: usize = 10;
+
+#[derive(Deserialize)]
+struct WithCharactersRequest {
+    // comma separated character ids, e.g. `1,2`
+    ids: String,
+}
+
+#[derive(Serialize)]
+struct CharacterResponse {
+    character: Option<Character>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct PersonResponse {
+    person: Option<Person>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct WithCharactersResponse {
+    games: Vec<Characteristic>,
+    message: String,
+}
+
+async fn character(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_character(id.into_inner()).await {
+        Ok(character) => HttpResponse::Ok().json(CharacterResponse {
+            character: Some(character),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching character: {}", err);
+            HttpResponse::BadGateway().json(CharacterResponse {
+                character: None,
+                message: "Failed to get character".to_string(),
+            })
+        }
+    }
+}
+
+async fn person(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_person(id.into_inner()).await {
+        Ok(person) => HttpResponse::Ok().json(PersonResponse {
+            person: Some(person),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching person: {}", err);
+            HttpResponse::BadGateway().json(PersonResponse {
+                person: None,
+                message: "Failed to get person".to_string(),
+            })
+        }
+    }
+}
+
+async fn games_with_characters(
+    params: web::Query<WithCharactersRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let ids = match params
+        .ids
+        .split(',')
+        .map(|id| id.trim().parse::<i32>())
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(ids) if !ids.is_empty() && ids.len() <= MAX_CHARACTERS => ids,
+        _ => {
+            return HttpResponse::BadRequest().json(WithCharactersResponse {
+                games: Vec::new(),
+                message: format!(
+                    "ids must be a comma separated list of 1 to {} character ids",
+                    MAX_CHARACTERS
+                ),
+            })
+        }
+    };
+
+    match ctx.gb.get_games_with_characters(&ids).await {
+        Ok(games) => HttpResponse::Ok().json(WithCharactersResponse {
+            games,
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching games with characters: {}", err);
+            HttpResponse::BadGateway().json(WithCharactersResponse {
+                games: Vec::new(),
+                message: "Failed to get games with characters".to_string(),
+            })
+        }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/characters/{id}", web::get().to(character))
+        .route("/people/{id}", web::get().to(person))
+        .route(
+            "/games/with-characters",
+            web::get().to(games_with_characters),
+        );
+}
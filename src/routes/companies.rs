@@ -0,0 +1,41 @@
+use super::PageRequest;
+use crate::gb_client::{Company, CompanyGame, Page};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct CompanyResponse {
+    company: Option<Company>,
+    games: Option<Page<CompanyGame>>,
+    message: String,
+}
+
+async fn company(
+    id: web::Path<i32>,
+    params: web::Query<PageRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    match ctx.gb.get_company(id.into_inner()).await {
+        Ok(mut company) => {
+            let games = Page::from_vec(company.take_games(), params.offset(), params.limit());
+            HttpResponse::Ok().json(CompanyResponse {
+                company: Some(company),
+                games: Some(games),
+                message: "OK".to_string(),
+            })
+        }
+        Err(err) => {
+            tracing::error!("Error fetching company: {}", err);
+            HttpResponse::BadGateway().json(CompanyResponse {
+                company: None,
+                games: None,
+                message: "Failed to get company".to_string(),
+            })
+        }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/companies/{id}", web::get().to(company));
+}
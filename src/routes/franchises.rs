@@ -0,0 +1,53 @@
+use crate::gb_client::{Franchise, FranchiseTimeline};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct FranchiseResponse {
+    franchise: Option<Franchise>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct TimelineResponse {
+    timeline: Option<FranchiseTimeline>,
+    message: String,
+}
+
+async fn franchise(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_franchise(id.into_inner()).await {
+        Ok(franchise) => HttpResponse::Ok().json(FranchiseResponse {
+            franchise: Some(franchise),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching franchise: {}", err);
+            HttpResponse::BadGateway().json(FranchiseResponse {
+                franchise: None,
+                message: "Failed to get franchise".to_string(),
+            })
+        }
+    }
+}
+
+async fn timeline(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_franchise_timeline(id.into_inner()).await {
+        Ok(timeline) => HttpResponse::Ok().json(TimelineResponse {
+            timeline: Some(timeline),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error building franchise timeline: {}", err);
+            HttpResponse::BadGateway().json(TimelineResponse {
+                timeline: None,
+                message: "Failed to get franchise timeline".to_string(),
+            })
+        }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/franchises/{id}", web::get().to(franchise))
+        .route("/franchises/{id}/timeline", web::get().to(timeline));
+}
@@ -0,0 +1,264 @@
+use crate::gb_client::{
+    group_by_region, Dlc, ExpandField, Game, GameBatch, GameFilter, RegionReleases, ReviewSummary,
+};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use futures::stream::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Deserialize)]
+struct GameRequest {
+    // comma separated extras to embed, e.g. `reviews,dlcs`
+    include: Option<String>,
+    // comma separated references to hydrate, e.g. `platforms,developers`
+    expand: Option<String>,
+}
+
+// the optional extras of the game detail route, each one costs extra upstream calls
+#[derive(Default)]
+struct Includes {
+    reviews: bool,
+    dlcs: bool,
+    expand: Vec<ExpandField>,
+}
+
+impl Includes {
+    fn parse(include: Option<&str>, expand: Option<&str>) -> Result<Self, String> {
+        let mut includes = Includes::default();
+        for part in include.unwrap_or_default().split(',').map(str::trim) {
+            match part {
+                "" => {}
+                "reviews" => includes.reviews = true,
+                "dlcs" => includes.dlcs = true,
+                other => return Err(format!("unknown include: {}", other)),
+            }
+        }
+        for part in expand.unwrap_or_default().split(',') {
+            if part.trim().is_empty() {
+                continue;
+            }
+            let field = part.parse::<ExpandField>()?;
+            if !includes.expand.contains(&field) {
+                includes.expand.push(field);
+            }
+        }
+        Ok(includes)
+    }
+}
+
+// GiantBomb takes 100 ids per request, this keeps a single batch to a handful of those
+const MAX_BATCH_SIZE: usize = 500;
+
+// keeps a single export from walking all of GiantBomb
+const DEFAULT_EXPORT_SIZE: usize = 1000;
+const MAX_EXPORT_SIZE: usize = 10_000;
+
+#[derive(Deserialize)]
+struct ExportRequest {
+    platform: Option<i32>,
+    // games per upstream request
+    page_size: Option<usize>,
+    // games in the whole export
+    max: Option<usize>,
+}
+
+#[derive(Deserialize)]
+struct BatchRequest {
+    ids: Vec<i32>,
+}
+
+#[derive(Serialize)]
+struct BatchResponse {
+    #[serde(flatten)]
+    batch: Option<GameBatch>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct GameDetailResponse {
+    game: Option<Game>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reviews: Option<ReviewSummary>,
+    // earliest first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dlcs: Option<Vec<Dlc>>,
+    // full objects behind the references asked for with `?expand=`, by field
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expanded: Option<BTreeMap<&'static str, Vec<serde_json::Value>>>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct DlcResponse {
+    dlc: Option<Dlc>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct ReleasesResponse {
+    regions: Vec<RegionReleases>,
+    message: String,
+}
+
+async fn game(
+    id: web::Path<i32>,
+    params: web::Query<GameRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let id = id.into_inner();
+    let includes = match Includes::parse(params.include.as_deref(), params.expand.as_deref()) {
+        Ok(includes) => includes,
+        Err(err) => {
+            return HttpResponse::BadRequest().json(GameDetailResponse {
+                game: None,
+                reviews: None,
+                dlcs: None,
+                expanded: None,
+                message: err,
+            })
+        }
+    };
+
+    let reviews = async {
+        if includes.reviews {
+            ctx.gb.get_review_summary(id).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    };
+
+    let dlcs = async {
+        if includes.dlcs {
+            ctx.gb.get_dlcs_for_game(id).await.map(Some)
+        } else {
+            Ok(None)
+        }
+    };
+
+    // the references only exist once we have the game, so expanding waits for it
+    let game = async {
+        let game = ctx.gb.get_game(id).await?;
+        if includes.expand.is_empty() {
+            return Ok((game, None));
+        }
+        let expanded = ctx.gb.expand_game(&game, &includes.expand).await;
+        Ok((game, Some(expanded)))
+    };
+
+    match futures::try_join!(game, reviews, dlcs) {
+        Ok(((game, expanded), reviews, dlcs)) => HttpResponse::Ok().json(GameDetailResponse {
+            game: Some(game),
+            reviews,
+            dlcs,
+            expanded,
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching game: {}", err);
+            HttpResponse::BadGateway().json(GameDetailResponse {
+                game: None,
+                reviews: None,
+                dlcs: None,
+                expanded: None,
+                message: "Failed to get game".to_string(),
+            })
+        }
+    }
+}
+
+async fn releases(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_releases_for_game(id.into_inner()).await {
+        Ok(releases) => HttpResponse::Ok().json(ReleasesResponse {
+            regions: group_by_region(releases),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching releases: {}", err);
+            HttpResponse::BadGateway().json(ReleasesResponse {
+                regions: Vec::new(),
+                message: "Failed to get releases".to_string(),
+            })
+        }
+    }
+}
+
+async fn batch(body: web::Json<BatchRequest>, ctx: web::Data<AppContext>) -> impl Responder {
+    if body.ids.is_empty() || body.ids.len() > MAX_BATCH_SIZE {
+        return HttpResponse::BadRequest().json(BatchResponse {
+            batch: None,
+            message: format!("ids must hold between 1 and {} ids", MAX_BATCH_SIZE),
+        });
+    }
+
+    match ctx.gb.get_games_by_ids(&body.ids).await {
+        Ok(batch) => HttpResponse::Ok().json(BatchResponse {
+            batch: Some(batch),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching games batch: {}", err);
+            HttpResponse::BadGateway().json(BatchResponse {
+                batch: None,
+                message: "Failed to get games".to_string(),
+            })
+        }
+    }
+}
+
+// newline delimited json, one game per line, streamed as GiantBomb pages come in
+async fn export(params: web::Query<ExportRequest>, ctx: web::Data<AppContext>) -> impl Responder {
+    let filter = GameFilter {
+        platform: params.platform,
+    };
+    let max = params
+        .max
+        .unwrap_or(DEFAULT_EXPORT_SIZE)
+        .clamp(1, MAX_EXPORT_SIZE);
+    let games = ctx
+        .gb
+        .stream_games(&filter, params.page_size.unwrap_or(100))
+        .take(max)
+        .and_then(|game| async move {
+            let mut line = serde_json::to_vec(&game)?;
+            line.push(b'\n');
+            Ok(web::Bytes::from(line))
+        })
+        // the status is long gone by the time a later page (or game) fails, all we can do is
+        // cut the body
+        .map_err(|err| {
+            tracing::error!("Error exporting games: {}", err);
+            actix_web::error::ErrorBadGateway("Failed to export games")
+        })
+        .boxed_local();
+
+    HttpResponse::Ok()
+        .content_type("application/x-ndjson")
+        .streaming(games)
+}
+
+async fn dlc(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_dlc(id.into_inner()).await {
+        Ok(dlc) => HttpResponse::Ok().json(DlcResponse {
+            dlc: Some(dlc),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching dlc: {}", err);
+            HttpResponse::BadGateway().json(DlcResponse {
+                dlc: None,
+                message: "Failed to get dlc".to_string(),
+            })
+        }
+    }
+}
+
+// registered after `/games/random` and `/games/with-characters` so those win over `{id}`,
+// same goes for `/games/export` within this file
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/games/batch", web::post().to(batch))
+        .route("/games/export", web::get().to(export))
+        .route("/games/{id}", web::get().to(game))
+        .route("/games/{id}/releases", web::get().to(releases))
+        .route("/dlcs/{id}", web::get().to(dlc));
+}
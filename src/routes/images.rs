@@ -0,0 +1,87 @@
+use super::PageRequest;
+use crate::gb_client::{is_guid, Image, Page};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct ImagesRequest {
+    // e.g. `Screenshots` or `Box Art`
+    tag: Option<String>,
+    offset: Option<i32>,
+    limit: Option<i32>,
+}
+
+impl ImagesRequest {
+    fn page(&self) -> PageRequest {
+        PageRequest {
+            offset: self.offset,
+            limit: self.limit,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct ImagesResponse {
+    images: Option<Page<Image>>,
+    message: String,
+}
+
+fn images_response(images: Result<Page<Image>, crate::gb_client::Error>) -> HttpResponse {
+    match images {
+        Ok(images) => HttpResponse::Ok().json(ImagesResponse {
+            images: Some(images),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching images: {}", err);
+            HttpResponse::BadGateway().json(ImagesResponse {
+                images: None,
+                message: "Failed to get images".to_string(),
+            })
+        }
+    }
+}
+
+async fn images(
+    guid: web::Path<String>,
+    params: web::Query<ImagesRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    if !is_guid(&guid) {
+        return HttpResponse::BadRequest().json(ImagesResponse {
+            images: None,
+            message: format!("invalid guid: {}", guid),
+        });
+    }
+
+    let page = params.page();
+    images_response(
+        ctx.gb
+            .get_images(&guid, params.tag.as_deref(), page.offset(), page.limit())
+            .await,
+    )
+}
+
+async fn game_images(
+    id: web::Path<i32>,
+    params: web::Query<ImagesRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let page = params.page();
+    images_response(
+        ctx.gb
+            .get_game_images(
+                id.into_inner(),
+                params.tag.as_deref(),
+                page.offset(),
+                page.limit(),
+            )
+            .await,
+    )
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/images/{guid}", web::get().to(images))
+        .route("/games/{id}/images", web::get().to(game_images));
+}
@@ -0,0 +1,91 @@
+use super::PageRequest;
+use crate::gb_client::{Game, GameFilter, Page, Platform};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct PlatformResponse {
+    platform: Option<Platform>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct PlatformsResponse {
+    platforms: Option<Page<Platform>>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct PlatformGamesResponse {
+    games: Option<Page<Game>>,
+    message: String,
+}
+
+async fn list_platforms(
+    params: web::Query<PageRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    match ctx.gb.list_platforms(params.offset(), params.limit()).await {
+        Ok(platforms) => HttpResponse::Ok().json(PlatformsResponse {
+            platforms: Some(platforms),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing platforms: {}", err);
+            HttpResponse::BadGateway().json(PlatformsResponse {
+                platforms: None,
+                message: "Failed to list platforms".to_string(),
+            })
+        }
+    }
+}
+
+async fn platform(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_platform(id.into_inner()).await {
+        Ok(platform) => HttpResponse::Ok().json(PlatformResponse {
+            platform: Some(platform),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching platform: {}", err);
+            HttpResponse::BadGateway().json(PlatformResponse {
+                platform: None,
+                message: "Failed to get platform".to_string(),
+            })
+        }
+    }
+}
+
+async fn platform_games(
+    id: web::Path<i32>,
+    params: web::Query<PageRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let filter = GameFilter {
+        platform: Some(id.into_inner()),
+    };
+    match ctx
+        .gb
+        .list_games(&filter, params.offset(), params.limit())
+        .await
+    {
+        Ok(games) => HttpResponse::Ok().json(PlatformGamesResponse {
+            games: Some(games),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing platform games: {}", err);
+            HttpResponse::BadGateway().json(PlatformGamesResponse {
+                games: None,
+                message: "Failed to list platform games".to_string(),
+            })
+        }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/platforms", web::get().to(list_platforms))
+        .route("/platforms/{id}", web::get().to(platform))
+        .route("/platforms/{id}/games", web::get().to(platform_games));
+}
@@ -0,0 +1,53 @@
+use crate::gb_client::{GameRating, RatingBoard};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct GameRatingsResponse {
+    ratings: Vec<GameRating>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct RatingBoardsResponse {
+    boards: Vec<RatingBoard>,
+    message: String,
+}
+
+async fn list_game_ratings(ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.list_game_ratings().await {
+        Ok(ratings) => HttpResponse::Ok().json(GameRatingsResponse {
+            ratings,
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing game ratings: {}", err);
+            HttpResponse::BadGateway().json(GameRatingsResponse {
+                ratings: Vec::new(),
+                message: "Failed to list game ratings".to_string(),
+            })
+        }
+    }
+}
+
+async fn list_rating_boards(ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.list_rating_boards().await {
+        Ok(boards) => HttpResponse::Ok().json(RatingBoardsResponse {
+            boards,
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing rating boards: {}", err);
+            HttpResponse::BadGateway().json(RatingBoardsResponse {
+                boards: Vec::new(),
+                message: "Failed to list rating boards".to_string(),
+            })
+        }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/game_ratings", web::get().to(list_game_ratings))
+        .route("/rating_boards", web::get().to(list_rating_boards));
+}
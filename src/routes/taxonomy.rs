@@ -0,0 +1,131 @@
+use super::PageRequest;
+use crate::gb_client::{Game, Page, Taxon, TaxonomyKind};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct TaxonomyResponse {
+    taxa: Option<Page<Taxon>>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct TaxonResponse {
+    taxon: Option<Taxon>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct TaxonGamesResponse {
+    games: Option<Page<Game>>,
+    message: String,
+}
+
+async fn list_taxonomy(
+    kind: web::Path<String>,
+    params: web::Query<PageRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let kind = match kind.parse::<TaxonomyKind>() {
+        Ok(kind) => kind,
+        Err(err) => {
+            return HttpResponse::BadRequest().json(TaxonomyResponse {
+                taxa: None,
+                message: err,
+            })
+        }
+    };
+
+    match ctx
+        .gb
+        .list_taxonomy(kind, params.offset(), params.limit())
+        .await
+    {
+        Ok(taxa) => HttpResponse::Ok().json(TaxonomyResponse {
+            taxa: Some(taxa),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing {}: {}", kind, err);
+            HttpResponse::BadGateway().json(TaxonomyResponse {
+                taxa: None,
+                message: format!("Failed to list {}", kind),
+            })
+        }
+    }
+}
+
+async fn taxon(path: web::Path<(String, i32)>, ctx: web::Data<AppContext>) -> impl Responder {
+    let (kind, id) = path.into_inner();
+    let kind = match kind.parse::<TaxonomyKind>() {
+        Ok(kind) => kind,
+        Err(err) => {
+            return HttpResponse::BadRequest().json(TaxonResponse {
+                taxon: None,
+                message: err,
+            })
+        }
+    };
+
+    match ctx.gb.get_taxon(kind, id).await {
+        Ok(taxon) => HttpResponse::Ok().json(TaxonResponse {
+            taxon: Some(taxon),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching {} {}: {}", kind, id, err);
+            HttpResponse::BadGateway().json(TaxonResponse {
+                taxon: None,
+                message: format!("Failed to get {}", kind),
+            })
+        }
+    }
+}
+
+async fn taxon_games(
+    path: web::Path<(String, i32)>,
+    params: web::Query<PageRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let (kind, id) = path.into_inner();
+    let kind = match kind.parse::<TaxonomyKind>() {
+        Ok(kind) if kind.has_games() => kind,
+        Ok(kind) => {
+            return HttpResponse::BadRequest().json(TaxonGamesResponse {
+                games: None,
+                message: format!("GiantBomb doesn't list games by {}", kind),
+            })
+        }
+        Err(err) => {
+            return HttpResponse::BadRequest().json(TaxonGamesResponse {
+                games: None,
+                message: err,
+            })
+        }
+    };
+
+    match ctx
+        .gb
+        .get_taxon_games(kind, id, params.offset(), params.limit())
+        .await
+    {
+        Ok(games) => HttpResponse::Ok().json(TaxonGamesResponse {
+            games: Some(games),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing games of {} {}: {}", kind, id, err);
+            HttpResponse::BadGateway().json(TaxonGamesResponse {
+                games: None,
+                message: format!("Failed to list games of {}", kind),
+            })
+        }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/taxonomy/{kind}", web::get().to(list_taxonomy))
+        .route("/taxonomy/{kind}/{id}", web::get().to(taxon))
+        .route("/taxonomy/{kind}/{id}/games", web::get().to(taxon_games));
+}
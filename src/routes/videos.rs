@@ -0,0 +1,198 @@
+use super::PageRequest;
+use crate::gb_client::{Page, Video, VideoCategory, VideoFilter, VideoQuality, VideoShow};
+use crate::AppContext;
+use actix_web::{web, HttpResponse, Responder};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+struct VideosRequest {
+    show: Option<i32>,
+    category: Option<i32>,
+    offset: Option<i32>,
+    limit: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct GameVideosRequest {
+    // e.g. the Quick Looks category
+    category: Option<i32>,
+    // preferred encoding for `url`, falls back to the best one available
+    quality: Option<VideoQuality>,
+}
+
+// a video plus the one url a card should link to
+#[derive(Serialize)]
+struct VideoCard {
+    #[serde(flatten)]
+    video: Video,
+    quality: Option<VideoQuality>,
+    url: Option<String>,
+}
+
+impl VideoCard {
+    fn new(video: Video, quality: Option<VideoQuality>) -> Self {
+        let urls = video.urls();
+        let chosen = quality
+            .and_then(|q| urls.get(q).map(|url| (q, url)))
+            .or_else(|| urls.best())
+            .map(|(q, url)| (q, url.to_string()));
+
+        VideoCard {
+            quality: chosen.as_ref().map(|(q, _)| *q),
+            url: chosen.map(|(_, url)| url),
+            video,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct VideoResponse {
+    video: Option<Video>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct VideosResponse {
+    videos: Option<Page<Video>>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct GameVideosResponse {
+    videos: Vec<VideoCard>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct VideoShowsResponse {
+    shows: Option<Page<VideoShow>>,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct VideoCategoriesResponse {
+    categories: Vec<VideoCategory>,
+    message: String,
+}
+
+async fn list_videos(
+    params: web::Query<VideosRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    let params = params.into_inner();
+    let filter = VideoFilter {
+        show: params.show,
+        category: params.category,
+    };
+    let page = PageRequest {
+        offset: params.offset,
+        limit: params.limit,
+    };
+    match ctx
+        .gb
+        .list_videos(&filter, page.offset(), page.limit())
+        .await
+    {
+        Ok(videos) => HttpResponse::Ok().json(VideosResponse {
+            videos: Some(videos),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing videos: {}", err);
+            HttpResponse::BadGateway().json(VideosResponse {
+                videos: None,
+                message: "Failed to list videos".to_string(),
+            })
+        }
+    }
+}
+
+async fn video(id: web::Path<i32>, ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.get_video(id.into_inner()).await {
+        Ok(video) => HttpResponse::Ok().json(VideoResponse {
+            video: Some(video),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching video: {}", err);
+            HttpResponse::BadGateway().json(VideoResponse {
+                video: None,
+                message: "Failed to get video".to_string(),
+            })
+        }
+    }
+}
+
+async fn game_videos(
+    id: web::Path<i32>,
+    params: web::Query<GameVideosRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    match ctx
+        .gb
+        .get_videos_for_game(id.into_inner(), params.category)
+        .await
+    {
+        Ok(videos) => HttpResponse::Ok().json(GameVideosResponse {
+            videos: videos
+                .into_iter()
+                .map(|video| VideoCard::new(video, params.quality))
+                .collect(),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error fetching game videos: {}", err);
+            HttpResponse::BadGateway().json(GameVideosResponse {
+                videos: Vec::new(),
+                message: "Failed to get game videos".to_string(),
+            })
+        }
+    }
+}
+
+async fn list_video_shows(
+    params: web::Query<PageRequest>,
+    ctx: web::Data<AppContext>,
+) -> impl Responder {
+    match ctx
+        .gb
+        .list_video_shows(params.offset(), params.limit())
+        .await
+    {
+        Ok(shows) => HttpResponse::Ok().json(VideoShowsResponse {
+            shows: Some(shows),
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing video shows: {}", err);
+            HttpResponse::BadGateway().json(VideoShowsResponse {
+                shows: None,
+                message: "Failed to list video shows".to_string(),
+            })
+        }
+    }
+}
+
+async fn list_video_categories(ctx: web::Data<AppContext>) -> impl Responder {
+    match ctx.gb.list_video_categories().await {
+        Ok(categories) => HttpResponse::Ok().json(VideoCategoriesResponse {
+            categories,
+            message: "OK".to_string(),
+        }),
+        Err(err) => {
+            tracing::error!("Error listing video categories: {}", err);
+            HttpResponse::BadGateway().json(VideoCategoriesResponse {
+                categories: Vec::new(),
+                message: "Failed to list video categories".to_string(),
+            })
+        }
+    }
+}
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.route("/videos", web::get().to(list_videos))
+        .route("/videos/{id}", web::get().to(video))
+        .route("/video_shows", web::get().to(list_video_shows))
+        .route("/video_categories", web::get().to(list_video_categories))
+        .route("/games/{id}/videos", web::get().to(game_videos));
+}
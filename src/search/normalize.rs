@@ -0,0 +1,77 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+// single letters (i, v, x) are left alone, too many titles use them as plain words
+const ROMAN_NUMERALS: &[(&str, &str)] = &[
+    ("ii", "2"),
+    ("iii", "3"),
+    ("iv", "4"),
+    ("vi", "6"),
+    ("vii", "7"),
+    ("viii", "8"),
+    ("ix", "9"),
+    ("xi", "11"),
+    ("xii", "12"),
+    ("xiii", "13"),
+    ("xiv", "14"),
+    ("xv", "15"),
+    ("xvi", "16"),
+    ("xvii", "17"),
+    ("xviii", "18"),
+    ("xix", "19"),
+    ("xx", "20"),
+];
+
+fn arabic(token: &str) -> &str {
+    ROMAN_NUMERALS
+        .iter()
+        .find(|(roman, _)| *roman == token)
+        .map(|(_, number)| *number)
+        .unwrap_or(token)
+}
+
+// folds a query (or a game name) into a canonical form so that
+// "Pokémon", "POKEMON" and "pokemon" all compare equal:
+// NFKD + diacritics dropped, lowercased, punctuation turned into spaces
+// (apostrophes just vanish), roman numerals turned into digits
+pub fn normalize(query: &str) -> String {
+    let folded = query
+        .nfkd()
+        .filter(|c| !is_combining_mark(*c))
+        .filter(|c| *c != '\'' && *c != '’')
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect::<String>()
+        .to_lowercase();
+
+    folded
+        .split_whitespace()
+        .map(arabic)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize;
+
+    #[test]
+    fn folds_diacritics_and_case() {
+        assert_eq!(normalize("Pokémon"), "pokemon");
+        assert_eq!(normalize("POKEMON"), "pokemon");
+        assert_eq!(normalize("Ｆｉｆａ"), "fifa");
+    }
+
+    #[test]
+    fn converts_roman_numerals() {
+        assert_eq!(normalize("Halo II"), "halo 2");
+        assert_eq!(normalize("Final Fantasy VII"), "final fantasy 7");
+        assert_eq!(normalize("Mega Man X"), "mega man x");
+    }
+
+    #[test]
+    fn strips_punctuation() {
+        assert_eq!(normalize("Zelda: Link"), "zelda link");
+        assert_eq!(normalize("  Assassin's   Creed "), "assassins creed");
+        assert_eq!(normalize("Half-Life 2"), "half life 2");
+    }
+}
@@ -0,0 +1,164 @@
+use super::edit_distance;
+use super::normalize::normalize;
+use crate::gb_client::Game;
+use serde::Serialize;
+use std::collections::HashMap;
+
+const EXACT_SCORE: f64 = 100.0;
+const ALIAS_SCORE: f64 = 80.0;
+const PREFIX_SCORE: f64 = 60.0;
+// fuzzy matches score up to this much, scaled by how similar the names are
+const FUZZY_SCORE: f64 = 50.0;
+// below this similarity (0..1) a name isn't considered a match at all
+const FUZZY_THRESHOLD: f64 = 0.6;
+
+const RELEASED_BOOST: f64 = 5.0;
+const IMAGE_BOOST: f64 = 5.0;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct RankedGame {
+    pub score: f64,
+    #[serde(flatten)]
+    pub game: Game,
+}
+
+fn similarity(a: &str, b: &str) -> f64 {
+    let longest = a.chars().count().max(b.chars().count());
+    if longest == 0 {
+        return 0.0;
+    }
+    1.0 - edit_distance(a, b) as f64 / longest as f64
+}
+
+fn name_score(query: &str, name: &str) -> f64 {
+    let name = normalize(name);
+    if name == query {
+        return EXACT_SCORE;
+    }
+    if name.starts_with(query) {
+        return PREFIX_SCORE;
+    }
+
+    let similarity = similarity(query, &name);
+    if similarity >= FUZZY_THRESHOLD {
+        FUZZY_SCORE * similarity
+    } else {
+        0.0
+    }
+}
+
+fn score(query: &str, game: &Game) -> f64 {
+    let alias_score = game
+        .aliases
+        .as_deref()
+        .unwrap_or_default()
+        .lines()
+        .map(str::trim)
+        .filter(|alias| !alias.is_empty())
+        .map(|alias| match name_score(query, alias) {
+            s if s >= EXACT_SCORE => ALIAS_SCORE,
+            s => s,
+        })
+        .fold(0.0, f64::max);
+
+    let mut score = name_score(query, &game.name).max(alias_score);
+    if game.original_release_date.is_some() {
+        score += RELEASED_BOOST;
+    }
+    if game.image.is_some() {
+        score += IMAGE_BOOST;
+    }
+    score
+}
+
+// scores every game against the query, drops duplicate guids (keeping the best scoring copy)
+// and orders best first; ties keep GiantBomb's original order
+pub fn rank(query: &str, games: Vec<Game>) -> Vec<RankedGame> {
+    let query = normalize(query);
+
+    let mut ranked: Vec<RankedGame> = Vec::with_capacity(games.len());
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    for game in games {
+        let score = score(&query, &game);
+        match seen.get(&game.guid) {
+            Some(&idx) => {
+                if score > ranked[idx].score {
+                    ranked[idx] = RankedGame { score, game };
+                }
+            }
+            None => {
+                seen.insert(game.guid.clone(), ranked.len());
+                ranked.push(RankedGame { score, game });
+            }
+        }
+    }
+
+    ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rank;
+    use crate::gb_client::Game;
+
+    fn game(guid: &str, name: &str) -> Game {
+        let mut game = Game::default();
+        game.guid = guid.to_string();
+        game.name = name.to_string();
+        game
+    }
+
+    #[test]
+    fn exact_match_beats_loosely_related_games() {
+        let games = vec![
+            game("3030-1", "Halo: Reach"),
+            game("3030-2", "Halo Wars"),
+            game("3030-3", "Halo"),
+        ];
+
+        let ranked = rank("halo", games);
+
+        assert_eq!(ranked[0].game.name, "Halo");
+        assert!(ranked[0].score > ranked[1].score);
+    }
+
+    #[test]
+    fn matches_aliases_and_typos() {
+        let mut zelda = game("3030-1", "The Legend of Zelda: A Link to the Past");
+        zelda.aliases = Some("Zelda 3\nALttP".to_string());
+        let games = vec![game("3030-2", "Metroid"), zelda];
+
+        let by_alias = rank("alttp", games.clone());
+        let by_typo = rank("metriod", games);
+
+        assert_eq!(by_alias[0].game.guid, "3030-1");
+        assert_eq!(by_typo[0].game.guid, "3030-2");
+        assert!(by_typo[0].score > 0.0);
+    }
+
+    #[test]
+    fn boosts_released_games_with_images() {
+        let mut released = game("3030-2", "Doom");
+        released.original_release_date = Some("1993-12-10".to_string());
+        let mut pictured = released.clone();
+        pictured.guid = "3030-3".to_string();
+        pictured.image = serde_json::from_value(serde_json::json!({})).unwrap();
+        let games = vec![game("3030-1", "Doom"), released, pictured];
+
+        let ranked = rank("doom", games);
+
+        let guids = ranked
+            .iter()
+            .map(|r| r.game.guid.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(guids, vec!["3030-3", "3030-2", "3030-1"]);
+    }
+
+    #[test]
+    fn deduplicates_by_guid() {
+        let games = vec![game("3030-1", "Doom"), game("3030-1", "Doom")];
+
+        assert_eq!(rank("doom", games).len(), 1);
+    }
+}
@@ -0,0 +1,205 @@
+use super::edit_distance;
+use super::normalize::normalize;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+const DICTIONARY_CAPACITY: usize = 50_000;
+const MAX_SUGGESTIONS: usize = 3;
+
+// american soundex, so "metroyd" and "metroid" land on the same code
+fn soundex(word: &str) -> Option<String> {
+    fn code(c: char) -> Option<char> {
+        match c {
+            'b' | 'f' | 'p' | 'v' => Some('1'),
+            'c' | 'g' | 'j' | 'k' | 'q' | 's' | 'x' | 'z' => Some('2'),
+            'd' | 't' => Some('3'),
+            'l' => Some('4'),
+            'm' | 'n' => Some('5'),
+            'r' => Some('6'),
+            _ => None,
+        }
+    }
+
+    let mut letters = word
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase());
+    let first = letters.next()?;
+
+    let mut out = first.to_ascii_uppercase().to_string();
+    let mut last = code(first);
+    for c in letters {
+        let current = code(c);
+        if let Some(digit) = current.filter(|_| current != last) {
+            out.push(digit);
+        }
+        // h and w don't separate letters with the same code, vowels do
+        if c != 'h' && c != 'w' {
+            last = current;
+        }
+        if out.len() == 4 {
+            break;
+        }
+    }
+    while out.len() < 4 {
+        out.push('0');
+    }
+    Some(out)
+}
+
+// a phrase sounds alike when each of its words do
+fn phonetic_key(phrase: &str) -> String {
+    phrase
+        .split_whitespace()
+        .map(|word| soundex(word).unwrap_or_else(|| word.to_string()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Default)]
+struct Entries {
+    // normalized name -> name as GiantBomb spells it
+    names: HashMap<String, String>,
+    // normalized names by their length in chars, an edit changes the length by at most one
+    by_len: HashMap<usize, Vec<String>>,
+    // normalized names by their phonetic key, worked out once when they're inserted
+    by_sound: HashMap<String, Vec<String>>,
+}
+
+// game names we've seen come back from GiantBomb (or were seeded with, see `load`), used to
+// correct queries that find nothing
+#[derive(Default)]
+pub struct Dictionary {
+    entries: RwLock<Entries>,
+}
+
+impl Dictionary {
+    pub fn insert(&self, name: &str) {
+        let key = normalize(name);
+        if key.is_empty() {
+            return;
+        }
+
+        let mut entries = self.entries.write().unwrap();
+        if let Some(known) = entries.names.get_mut(&key) {
+            *known = name.trim().to_string();
+            return;
+        }
+        if entries.names.len() >= DICTIONARY_CAPACITY {
+            return;
+        }
+        entries
+            .by_len
+            .entry(key.chars().count())
+            .or_default()
+            .push(key.clone());
+        entries
+            .by_sound
+            .entry(phonetic_key(&key))
+            .or_default()
+            .push(key.clone());
+        entries.names.insert(key, name.trim().to_string());
+    }
+
+    pub fn extend<'a>(&self, names: impl IntoIterator<Item = &'a str>) {
+        names.into_iter().for_each(|name| self.insert(name));
+    }
+
+    // seeds the dictionary from a local mirror of game names, one per line
+    pub fn load(&self, path: &str) -> std::io::Result<usize> {
+        let contents = std::fs::read_to_string(path)?;
+        self.extend(contents.lines());
+        Ok(self.len())
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().names.len()
+    }
+
+    // closest known names by edit distance, falling back to names that sound the same.
+    // only names close enough in length are compared, so a lookup never walks everything
+    pub fn suggest(&self, query: &str) -> Vec<String> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let len = query.chars().count();
+        let max_distance = (len / 3).max(1);
+
+        let entries = self.entries.read().unwrap();
+        let mut candidates = HashMap::new();
+        for key in (len.saturating_sub(max_distance)..=len + max_distance)
+            .filter_map(|len| entries.by_len.get(&len))
+            .flatten()
+        {
+            let distance = edit_distance(&query, key);
+            if distance <= max_distance {
+                candidates.insert(key, distance);
+            }
+        }
+        for key in entries
+            .by_sound
+            .get(&phonetic_key(&query))
+            .into_iter()
+            .flatten()
+        {
+            candidates.entry(key).or_insert(max_distance + 1);
+        }
+
+        let mut candidates = candidates
+            .into_iter()
+            .filter(|(key, _)| **key != query)
+            .filter_map(|(key, distance)| entries.names.get(key).map(|name| (distance, name)))
+            .collect::<Vec<_>>();
+        candidates.sort();
+        candidates
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, name)| name.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{soundex, Dictionary};
+
+    #[test]
+    fn encodes_soundex() {
+        assert_eq!(soundex("Robert"), Some("R163".to_string()));
+        assert_eq!(soundex("Ashcraft"), Some("A261".to_string()));
+        assert_eq!(soundex("Tymczak"), Some("T522".to_string()));
+        assert_eq!(soundex("123"), None);
+    }
+
+    #[test]
+    fn suggests_close_names() {
+        let dictionary = Dictionary::default();
+        dictionary.extend(vec!["Metroid", "Metroid Prime", "Halo", "Hollow Knight"]);
+
+        assert_eq!(dictionary.suggest("metriod"), vec!["Metroid".to_string()]);
+        assert_eq!(
+            dictionary.suggest("holow  knigt"),
+            vec!["Hollow Knight".to_string()]
+        );
+        // too many edits away, but it sounds right
+        assert_eq!(
+            dictionary.suggest("mettrowwyd"),
+            vec!["Metroid".to_string()]
+        );
+        assert!(dictionary.suggest("pizza").is_empty());
+    }
+
+    #[test]
+    fn loads_names_from_a_mirror() {
+        let path = std::env::temp_dir().join(format!("dictionary-{}.txt", std::process::id()));
+        std::fs::write(&path, "Metroid\n\nHalo\nmetroid\n").unwrap();
+
+        let dictionary = Dictionary::default();
+        let loaded = dictionary.load(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded, 2);
+        assert_eq!(dictionary.suggest("hallo"), vec!["Halo".to_string()]);
+    }
+}
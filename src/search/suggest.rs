@@ -0,0 +1,272 @@
+use super::normalize::normalize;
+use crate::gb_client::{Game, GiantBombClient};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// anything shorter than this matches half of GiantBomb, so we don't bother asking
+const MIN_PREFIX_LEN: usize = 3;
+
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+const CACHE_CAPACITY: usize = 1000;
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    id: i32,
+    pub(crate) name: String,
+    thumb: Option<String>,
+    // kept so the content filter can be applied to cached suggestions, never sent out
+    #[serde(skip)]
+    ratings: Vec<String>,
+}
+
+impl Suggestion {
+    pub fn ratings(&self) -> impl Iterator<Item = &str> {
+        self.ratings.iter().map(String::as_str)
+    }
+}
+
+impl From<&Game> for Suggestion {
+    fn from(game: &Game) -> Self {
+        Suggestion {
+            id: game.id,
+            name: game.name.clone(),
+            thumb: game.image.as_ref().and_then(|img| img.thumb_url.clone()),
+            ratings: game.ratings().map(String::from).collect(),
+        }
+    }
+}
+
+// a normalized query long enough to be worth asking GiantBomb about
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Prefix(String);
+
+impl Prefix {
+    pub fn parse(query: &str) -> Result<Self, String> {
+        let key = normalize(query);
+        if key.chars().count() < MIN_PREFIX_LEN {
+            return Err(format!("q must be at least {} characters", MIN_PREFIX_LEN));
+        }
+        Ok(Prefix(key))
+    }
+}
+
+type Suggestions = Arc<Vec<Suggestion>>;
+
+// errors are flattened to strings so the shared future's output can be cloned
+type PendingSuggestions = Shared<BoxFuture<'static, Result<Suggestions, String>>>;
+
+struct CacheEntry {
+    suggestions: Suggestions,
+    inserted: Instant,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self) -> bool {
+        self.inserted.elapsed() < CACHE_TTL
+    }
+}
+
+#[derive(Default)]
+pub struct SuggestCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+    in_flight: Arc<Mutex<HashMap<String, PendingSuggestions>>>,
+}
+
+impl SuggestCache {
+    pub async fn suggest(
+        &self,
+        gb: &GiantBombClient,
+        prefix: &Prefix,
+    ) -> Result<Vec<Suggestion>, String> {
+        let key = &prefix.0;
+        if let Some(suggestions) = self.lookup(key) {
+            return Ok(suggestions);
+        }
+
+        let pending = {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            in_flight
+                .entry(key.clone())
+                .or_insert_with(|| {
+                    let gb = gb.clone();
+                    let term = key.clone();
+                    let in_flight = self.in_flight.clone();
+                    async move {
+                        let result = gb
+                            .search_by_game_name(&term)
+                            .await
+                            .map(|games| Arc::new(games.iter().map(Suggestion::from).collect()))
+                            .map_err(|err| err.to_string());
+                        // cleared by the call itself, even when nobody waits for it anymore
+                        in_flight.lock().unwrap().remove(&term);
+                        result
+                    }
+                    .boxed()
+                    .shared()
+                })
+                .clone()
+        };
+
+        let suggestions = pending.await?;
+        self.insert(key.clone(), suggestions.clone());
+        Ok(suggestions.to_vec())
+    }
+
+    // exact keys only: GiantBomb matches whole words and aliases, so a longer query can
+    // find games its prefix didn't, and narrowing a prefix's results would miss them
+    fn lookup(&self, key: &str) -> Option<Vec<Suggestion>> {
+        let entries = self.entries.lock().unwrap();
+        entries
+            .get(key)
+            .filter(|e| e.is_fresh())
+            .map(|e| e.suggestions.to_vec())
+    }
+
+    fn insert(&self, key: String, suggestions: Suggestions) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= CACHE_CAPACITY {
+            entries.retain(|_, e| e.is_fresh());
+        }
+        if entries.len() >= CACHE_CAPACITY {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, e)| e.inserted)
+                .map(|(k, _)| k.clone());
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(
+            key,
+            CacheEntry {
+                suggestions,
+                inserted: Instant::now(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Prefix, SuggestCache, Suggestion};
+    use crate::gb_client::tests::envelope;
+    use crate::gb_client::{ContentFilter, Game, GiantBombClient};
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::ResponseTemplate;
+    use wiremock::{Mock, MockServer};
+
+    fn search_response(names: &[&str]) -> serde_json::Value {
+        let results = names
+            .iter()
+            .enumerate()
+            .map(|(id, name)| serde_json::json!({ "id": id, "guid": format!("3030-{}", id), "name": name }))
+            .collect::<Vec<_>>();
+        envelope(results.into())
+    }
+
+    #[test]
+    fn rejects_short_prefixes() {
+        assert!(Prefix::parse(" ha ").is_err());
+        assert!(Prefix::parse("halo").is_ok());
+    }
+
+    #[test]
+    fn keeps_ratings_for_the_content_filter_but_never_sends_them() {
+        let game: Game = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "guid": "3030-1",
+            "name": "Doom",
+            "original_game_rating": [{ "api_detail_url": "", "id": 1, "name": "ESRB: M" }]
+        }))
+        .unwrap();
+
+        let suggestion = Suggestion::from(&game);
+
+        assert!(!ContentFilter::new(&["ESRB: M"]).allows_ratings(suggestion.ratings()));
+        assert_eq!(
+            serde_json::to_value(&suggestion).unwrap(),
+            serde_json::json!({ "id": 1, "name": "Doom", "thumb": null })
+        );
+    }
+
+    #[tokio::test]
+    async fn asks_upstream_again_for_longer_prefixes() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _short_guard = Mock::given(method("GET"))
+            .and(path("/api/search"))
+            .and(query_param("query", "zel"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(search_response(&["Zelda"])))
+            .named("GET search zel")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        // found through its "Zelda 3" alias, so narrowing "zel" by name would drop it
+        let _long_guard = Mock::given(method("GET"))
+            .and(path("/api/search"))
+            .and(query_param("query", "zelda 3"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(search_response(&["A Link to the Past"])),
+            )
+            .named("GET search zelda 3")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let gb = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+        let cache = SuggestCache::default();
+
+        // Act
+        let short = cache
+            .suggest(&gb, &Prefix::parse("zel").unwrap())
+            .await
+            .unwrap();
+        let long = cache
+            .suggest(&gb, &Prefix::parse("Zelda III").unwrap())
+            .await
+            .unwrap();
+        let cached = cache
+            .suggest(&gb, &Prefix::parse("zel").unwrap())
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(short[0].name, "Zelda");
+        assert_eq!(long.len(), 1);
+        assert_eq!(long[0].name, "A Link to the Past");
+        assert_eq!(cached, short);
+    }
+
+    #[tokio::test]
+    async fn coalesces_concurrent_identical_requests() {
+        // Arrange
+        let mock_gb_server = MockServer::start().await;
+        let _mock_guard = Mock::given(method("GET"))
+            .and(path("/api/search"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(search_response(&["Metroid"]))
+                    .set_delay(std::time::Duration::from_millis(100)),
+            )
+            .named("GET search")
+            .expect(1)
+            .mount_as_scoped(&mock_gb_server)
+            .await;
+        let gb = GiantBombClient::with_api_url("fake_token", &mock_gb_server.uri());
+        let cache = SuggestCache::default();
+
+        // Act
+        let (lower, upper) = (
+            Prefix::parse("metr").unwrap(),
+            Prefix::parse("METR").unwrap(),
+        );
+        let (a, b) = futures::join!(cache.suggest(&gb, &lower), cache.suggest(&gb, &upper));
+
+        // Assert
+        assert_eq!(a.unwrap(), b.unwrap());
+        assert!(cache.in_flight.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,120 @@
+use crate::gb_client;
+use std::env;
+
+// everything the server reads from the environment, see the README for the variables
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub gb_token: String,
+    // game ratings never served, e.g. `ESRB: M`, `PEGI: 18`
+    pub excluded_ratings: Vec<String>,
+    // how many references `?expand=` follows at once, across all requests
+    pub expand_concurrency: usize,
+    // how many random games are kept ready, 0 turns the pool off
+    pub random_pool_size: usize,
+    // pause between two games fetched for the pool, GiantBomb allows ~200 calls per hour
+    pub random_pool_refill_secs: u64,
+    // how long the number of games per filter is trusted, refreshed in the background at half that
+    pub catalog_ttl_secs: u64,
+    // GiantBomb responses bigger than this are refused
+    pub max_body_bytes: usize,
+    // game names, one per line, the spelling dictionary starts out with
+    pub dictionary_file: Option<String>,
+    // checks our hardcoded resources against GiantBomb's `/types` when the server starts
+    pub validate_types: bool,
+    // how many more times a failed GiantBomb call is tried
+    pub max_retries: u32,
+    // GiantBomb calls allowed at once, after that one per `rate_limit_interval_ms`
+    pub rate_limit_burst: u32,
+    // 0 turns the rate limit off
+    pub rate_limit_interval_ms: u64,
+    // how long an untyped GiantBomb response is answered from memory, 0 turns that off
+    pub raw_cache_ttl_secs: u64,
+}
+
+// comma separated env var, unset means empty
+fn list_var(name: &str) -> Vec<String> {
+    env::var(name)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+// on/off env var, `0`, `false`, `no` and `off` turn it off, unset means `default`
+fn flag_var(name: &str, default: bool) -> bool {
+    match env::var(name) {
+        Ok(value) => !matches!(
+            value.trim().to_lowercase().as_str(),
+            "0" | "false" | "no" | "off"
+        ),
+        Err(_) => default,
+    }
+}
+
+// numeric env var, unset or garbage means `default`
+fn number_var<N: std::str::FromStr>(name: &str, default: N) -> N {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .unwrap_or(default)
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            gb_token: String::new(),
+            excluded_ratings: Vec::new(),
+            expand_concurrency: gb_client::DEFAULT_EXPAND_CONCURRENCY,
+            random_pool_size: 10,
+            random_pool_refill_secs: 60,
+            catalog_ttl_secs: 6 * 60 * 60,
+            max_body_bytes: gb_client::DEFAULT_MAX_BODY_BYTES,
+            dictionary_file: None,
+            validate_types: true,
+            max_retries: 2,
+            rate_limit_burst: 5,
+            rate_limit_interval_ms: 1000,
+            raw_cache_ttl_secs: gb_client::DEFAULT_RAW_CACHE_TTL.as_secs(),
+        }
+    }
+}
+
+impl Settings {
+    // the defaults minus everything that calls GiantBomb on its own, so nothing goes
+    // upstream until a request asks for it
+    pub fn new(gb_token: &str) -> Self {
+        Settings {
+            gb_token: gb_token.to_string(),
+            validate_types: false,
+            random_pool_size: 0,
+            ..Settings::default()
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let defaults = Settings::default();
+        Settings {
+            gb_token: env::var("GB_TOKEN").expect("GB_TOKEN env is required"),
+            excluded_ratings: list_var("EXCLUDED_RATINGS"),
+            expand_concurrency: number_var("EXPAND_CONCURRENCY", defaults.expand_concurrency),
+            random_pool_size: number_var("RANDOM_POOL_SIZE", defaults.random_pool_size),
+            random_pool_refill_secs: number_var(
+                "RANDOM_POOL_REFILL_SECS",
+                defaults.random_pool_refill_secs,
+            ),
+            catalog_ttl_secs: number_var("CATALOG_TTL_SECS", defaults.catalog_ttl_secs),
+            max_body_bytes: number_var("MAX_BODY_BYTES", defaults.max_body_bytes),
+            dictionary_file: env::var("DICTIONARY_FILE").ok(),
+            validate_types: flag_var("VALIDATE_TYPES", defaults.validate_types),
+            max_retries: number_var("MAX_RETRIES", defaults.max_retries),
+            rate_limit_burst: number_var("RATE_LIMIT_BURST", defaults.rate_limit_burst),
+            rate_limit_interval_ms: number_var(
+                "RATE_LIMIT_INTERVAL_MS",
+                defaults.rate_limit_interval_ms,
+            ),
+            raw_cache_ttl_secs: number_var("RAW_CACHE_TTL_SECS", defaults.raw_cache_ttl_secs),
+        }
+    }
+}
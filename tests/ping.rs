@@ -24,8 +24,8 @@ fn spawn_app() -> String {
   let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind to random port");
   let port = listener.local_addr().unwrap().port();
 
-  let server = giantbomb_rs::srv(listener, fake_gb_token).expect("Failed to create server");
-  let _ = tokio::spawn(server);
+  let server = giantbomb_rs::srv(listener, giantbomb_rs::Settings::new(fake_gb_token)).expect("Failed to create server");
+  drop(tokio::spawn(server));
 
   format!("http://127.0.0.1:{}", port)
 }